@@ -0,0 +1,59 @@
+use std::hint::black_box;
+use std::time::Instant;
+
+use selecta::score::{Algorithm, Scorer};
+
+const ITERATIONS: u32 = 100;
+
+static PATHS: &str = include_str!("../tests/fixtures/paths.txt");
+
+/// Time `f` over a fixed number of iterations and report the mean.
+fn bench<F: FnMut()>(name: &str, mut f: F) {
+    // warm up
+    f();
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let per_iter = start.elapsed() / ITERATIONS;
+
+    println!("{:<30} {:>12?}/iter", name, per_iter);
+}
+
+fn main() {
+    for (name, algorithm) in [("selecta", Algorithm::Selecta),
+                              ("smith-waterman", Algorithm::SmithWaterman),
+                              ("substring", Algorithm::Substring)] {
+        println!("{}", name);
+        bench_scorer(algorithm.scorer());
+    }
+}
+
+fn bench_scorer(scorer: &dyn Scorer) {
+    bench("non_matching", || {
+        let _ = black_box(scorer.score(black_box("xxxxxxxxxxxxxxxxx"), black_box("yyyyyyyyyyyyyyy")));
+    });
+
+    bench("match_exactly", || {
+        let _ = black_box(scorer.score(black_box("xxxxxxxxxxxxxxxxx"), black_box("xxxxxxxxxxxxxxxxx")));
+    });
+
+    bench("paths_non_matching", || {
+        for choice in PATHS.lines() {
+            let _ = black_box(scorer.score(choice, black_box("xxxxxxxxxxxxxxx")));
+        }
+    });
+
+    bench("paths_empty_query", || {
+        for choice in PATHS.lines() {
+            let _ = black_box(scorer.score(choice, black_box("")));
+        }
+    });
+
+    bench("paths_trivial_query", || {
+        for choice in PATHS.lines() {
+            let _ = black_box(scorer.score(choice, black_box("a")));
+        }
+    });
+}
@@ -1,24 +1,33 @@
-use std::io::stdio::StdWriter;
+use std::io::Write;
 
-enum ANSIColor {
-    black,
-    red,
-    green,
-    yellow,
-    blue,
-    magenta,
-    cyan,
-    white,
-    default
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ANSIColor {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Default
 }
 
 fn escape(sequence: &str) -> String {
-    let ESC: char = ::std::char::from_u32(27).unwrap();
+    const ESC: char = '\x1b';
     format!("{}[{}", ESC, sequence)
 }
 
 fn clear() -> String {
-    escape("23")
+    escape("2J")
+}
+
+fn clear_line() -> String {
+    escape("K")
+}
+
+fn alternate_screen(on: bool) -> String {
+    escape(if on { "?1049h" } else { "?1049l" })
 }
 
 fn hide_cursor() -> String {
@@ -31,36 +40,40 @@ fn show_cursor() -> String {
 
 fn setpos(line: u16, column: u16) -> String {
     let seq = format!("{};{}H", (line + 1), (column + 1));
-    escape(seq.as_slice())
+    escape(&seq)
+}
+
+fn fg_code(color: ANSIColor) -> u8 {
+    match color {
+        ANSIColor::Black => 30,
+        ANSIColor::Red => 31,
+        ANSIColor::Green => 32,
+        ANSIColor::Yellow => 33,
+        ANSIColor::Blue => 34,
+        ANSIColor::Magenta => 35,
+        ANSIColor::Cyan => 36,
+        ANSIColor::White => 37,
+        ANSIColor::Default => 39,
+    }
 }
 
 fn color(fg: ANSIColor, bg: ANSIColor) -> String {
-    let fg_code = match fg {
-        ANSIColor::black => 30,
-        ANSIColor::red => 31,
-        ANSIColor::green => 32,
-        ANSIColor::yellow => 33,
-        ANSIColor::blue => 34,
-        ANSIColor::magenta => 35,
-        ANSIColor::cyan => 36,
-        ANSIColor::white => 37,
-        ANSIColor::default => 39,
-    };
+    let fg_code = fg_code(fg);
 
     let bg_code = match bg {
-        ANSIColor::black => 40,
-        ANSIColor::red => 41,
-        ANSIColor::green => 42,
-        ANSIColor::yellow => 44,
-        ANSIColor::blue => 44,
-        ANSIColor::magenta => 45,
-        ANSIColor::cyan => 46,
-        ANSIColor::white => 47,
-        ANSIColor::default => 49,
+        ANSIColor::Black => 40,
+        ANSIColor::Red => 41,
+        ANSIColor::Green => 42,
+        ANSIColor::Yellow => 43,
+        ANSIColor::Blue => 44,
+        ANSIColor::Magenta => 45,
+        ANSIColor::Cyan => 46,
+        ANSIColor::White => 47,
+        ANSIColor::Default => 49,
     };
 
     let seq = format!("{};{}m", fg_code, bg_code);
-    escape(seq.as_slice())
+    escape(&seq)
 }
 
 fn inverse() -> String {
@@ -71,20 +84,117 @@ fn reset() -> String {
     escape("0m")
 }
 
+fn style(style: &Style) -> String {
+    let mut codes = Vec::new();
+
+    if style.bold {
+        codes.push(1);
+    }
+    if style.underline {
+        codes.push(4);
+    }
+    if style.inverse {
+        codes.push(7);
+    }
+    if let Some(fg) = style.fg {
+        codes.push(fg_code(fg));
+    }
+
+    if codes.is_empty() {
+        return String::new();
+    }
+
+    let codes: Vec<String> = codes.iter().map(|c| c.to_string()).collect();
+    escape(&format!("{}m", codes.join(";")))
+}
+
+/// A combination of text attributes and a foreground color, used for the
+/// configurable parts of the picker.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Style {
+    pub bold: bool,
+    pub underline: bool,
+    pub inverse: bool,
+    pub fg: Option<ANSIColor>,
+}
+
+impl Style {
+    /// Parse a comma separated list of attributes and at most one color,
+    /// e.g. `bold,red` or `inverse`. `none` is the plain style.
+    pub fn parse(s: &str) -> Option<Style> {
+        let mut style = Style::default();
+
+        for word in s.split(',').map(|w| w.trim()) {
+            match word {
+                "none" => {},
+                "bold" => style.bold = true,
+                "underline" => style.underline = true,
+                "inverse" | "reverse" => style.inverse = true,
+                _ => {
+                    let color = parse_color(word)?;
+                    if style.fg.is_some() {
+                        return None;
+                    }
+                    style.fg = Some(color);
+                },
+            }
+        }
+
+        Some(style)
+    }
+}
+
+impl Style {
+    /// This style with `other` drawn on top: attributes add up and the
+    /// color of `other` wins.
+    pub fn combine(self, other: Style) -> Style {
+        Style { bold: self.bold || other.bold,
+                underline: self.underline || other.underline,
+                inverse: self.inverse || other.inverse,
+                fg: other.fg.or(self.fg) }
+    }
+}
+
+fn parse_color(name: &str) -> Option<ANSIColor> {
+    match name {
+        "black" => Some(ANSIColor::Black),
+        "red" => Some(ANSIColor::Red),
+        "green" => Some(ANSIColor::Green),
+        "yellow" => Some(ANSIColor::Yellow),
+        "blue" => Some(ANSIColor::Blue),
+        "magenta" => Some(ANSIColor::Magenta),
+        "cyan" => Some(ANSIColor::Cyan),
+        "white" => Some(ANSIColor::White),
+        "default" => Some(ANSIColor::Default),
+        _ => None,
+    }
+}
+
 
-pub struct ANSI {
-    file: StdWriter
+pub struct ANSI<W: Write> {
+    file: W
 }
 
-impl ANSI {
-    pub fn new(file: StdWriter) -> ANSI {
-        ANSI { file: file }
+impl<W: Write> ANSI<W> {
+    pub fn new(file: W) -> ANSI<W> {
+        ANSI { file }
     }
 
     pub fn clear(&mut self) {
         self.write(clear());
     }
 
+    /// Erase from the cursor to the end of the line.
+    pub fn clear_line(&mut self) {
+        self.write(clear_line());
+    }
+
+    /// Switch to the terminal's alternate screen, or back to the one that
+    /// was there before, as it was.
+    pub fn alternate_screen(&mut self, on: bool) {
+        self.write(alternate_screen(on));
+    }
+
     pub fn hide_cursor(&mut self) {
         self.write(hide_cursor());
     }
@@ -109,7 +219,61 @@ impl ANSI {
         self.write(reset());
     }
 
+    pub fn style(&mut self, s: &Style) {
+        self.write(style(s));
+    }
+
     pub fn write(&mut self, s: String) {
-        self.file.write_str(s.as_slice());
+        self.write_bytes(s.as_bytes());
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        let _ = self.file.write_all(bytes);
+    }
+
+    pub fn flush(&mut self) {
+        let _ = self.file.flush();
+    }
+
+    pub fn into_inner(self) -> W {
+        self.file
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_parses_styles() {
+        assert!(Style::parse("none") == Some(Style::default()));
+        assert!(Style::parse("inverse") == Some(Style { inverse: true, ..Style::default() }));
+        assert!(Style::parse("bold, red") == Some(Style { bold: true, fg: Some(ANSIColor::Red),
+                                                         ..Style::default() }));
+        assert!(Style::parse("underline,reverse") == Some(Style { underline: true, inverse: true,
+                                                                 ..Style::default() }));
+    }
+
+    #[test]
+    fn test_rejects_bad_styles() {
+        assert!(Style::parse("").is_none());
+        assert!(Style::parse("blink").is_none());
+        assert!(Style::parse("red,blue").is_none());
+    }
+
+    #[test]
+    fn test_writes_styles_as_one_sequence() {
+        assert!(style(&Style::parse("bold,underline,inverse,green").unwrap()) == "\x1b[1;4;7;32m");
+        assert!(style(&Style::default()).is_empty());
+    }
+
+    #[test]
+    fn test_combines_styles() {
+        let selected = Style { inverse: true, fg: Some(ANSIColor::Red), ..Style::default() };
+        let matched = Style { bold: true, fg: Some(ANSIColor::Green), ..Style::default() };
+        assert!(selected.combine(matched) == Style { bold: true, inverse: true, fg: Some(ANSIColor::Green),
+                                                     ..Style::default() });
+        assert!(matched.combine(Style::default()) == matched);
     }
 }
@@ -1,21 +1,45 @@
 use std::cmp::min;
 
+use crate::options::{Height, Options};
+use crate::score::Algorithm;
+
 pub struct Configuration {
     visible_choices: u8,
     initial_search: String,
     choices: Vec<String>,
+    wraparound: bool,
+    multi: bool,
+    extended: bool,
+    algorithm: Algorithm,
+    height: Height,
 }
 
 impl Configuration {
-    pub fn from_inputs(choices: Vec<String>, screen_height: u16) -> Configuration {
-        let visible_choices = min(20, screen_height as u8);
-        Configuration::new(visible_choices, String::from_str(""), choices)
+    pub fn from_inputs(choices: Vec<String>, options: &Options, screen_height: u16) -> Configuration {
+        let visible_choices = compute_visible_choices(options.height, screen_height);
+        let mut config = Configuration::new(visible_choices, options.search.clone(), choices);
+        config.wraparound = options.wraparound;
+        config.multi = options.multi;
+        config.extended = options.extended;
+        config.algorithm = options.algorithm;
+        config.height = options.height;
+        config
     }
 
     pub fn new(visible_choices: u8, initial_search: String, choices: Vec<String>) -> Configuration {
-        Configuration { visible_choices: visible_choices,
-                        initial_search: initial_search,
-                        choices: choices }
+        Configuration { visible_choices,
+                        initial_search,
+                        choices,
+                        wraparound: true,
+                        multi: false,
+                        extended: true,
+                        algorithm: Algorithm::Selecta,
+                        height: Height::Lines(visible_choices as u16 + 1) }
+    }
+
+    /// Recompute the number of visible choices for a new screen height.
+    pub fn resize(&mut self, screen_height: u16) {
+        self.visible_choices = compute_visible_choices(self.height, screen_height);
     }
 
     // TODO should i be using clone here?
@@ -30,4 +54,34 @@ impl Configuration {
     pub fn get_visible_choices(&self) -> u8 {
         self.visible_choices
     }
+
+    /// Whether moving past either end of the match list wraps around to
+    /// the other end.
+    pub fn get_wraparound(&self) -> bool {
+        self.wraparound
+    }
+
+    /// Whether several choices can be marked and picked at once.
+    pub fn get_multi(&self) -> bool {
+        self.multi
+    }
+
+    /// Whether queries use the extended syntax, or are matched as typed.
+    pub fn get_extended(&self) -> bool {
+        self.extended
+    }
+
+    pub fn get_algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+}
+
+fn compute_visible_choices(height: Height, screen_height: u16) -> u8 {
+    let height = match height {
+        Height::Full => screen_height,
+        Height::Lines(lines) => min(lines, screen_height),
+    };
+
+    // Actual height is one less because the prompt takes a line.
+    min(height.saturating_sub(1), u8::MAX as u16) as u8
 }
@@ -0,0 +1,289 @@
+use std::io::Write;
+
+use crate::ansi::{Style, ANSI};
+
+/// One column on the screen and how it's drawn.
+///
+/// A double-width char takes two cells: the char itself, then a
+/// continuation cell with no width of its own for the column it covers.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Cell {
+    pub ch: char,
+    pub style: Style,
+    pub width: u8,
+}
+
+impl Cell {
+    /// A cell for the char. Control chars are drawn as `?` so text from
+    /// the input can't move the cursor or start an escape sequence.
+    pub fn new(ch: char, style: Style) -> Cell {
+        let ch = if ch.is_control() { '?' } else { ch };
+        Cell { ch, style, width: char_width(ch) as u8 }
+    }
+
+    /// The second column of a double-width char.
+    fn continuation(style: Style) -> Cell {
+        Cell { ch: ' ', style, width: 0 }
+    }
+
+    fn is_continuation(&self) -> bool {
+        self.width == 0
+    }
+}
+
+/// How many columns the char takes up on the terminal: two for the East
+/// Asian wide and fullwidth ranges, one for everything else.
+pub fn char_width(c: char) -> usize {
+    match c as u32 {
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        | 0xA960..=0xA97F
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE10..=0xFE19
+        | 0xFE30..=0xFE6F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F
+        | 0x1F900..=0x1F9FF
+        | 0x20000..=0x2FFFD
+        | 0x30000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
+
+/// How many columns the text takes up on the terminal.
+pub fn text_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+/// The contents of the picker for one redraw, line by line.
+///
+/// Rather than repainting everything, a frame is compared with the one
+/// drawn before it and only the lines that changed are written, starting
+/// at their first changed cell.
+#[derive(Debug, PartialEq)]
+pub struct Frame {
+    width: usize,
+    lines: Vec<Vec<Cell>>,
+}
+
+impl Frame {
+    pub fn new(width: u16, height: u16) -> Frame {
+        Frame { width: width as usize, lines: vec![Vec::new(); height as usize] }
+    }
+
+    /// Replace a line, cutting it off at the frame width. A double-width
+    /// char that doesn't fit is replaced with a space. Lines outside the
+    /// frame are ignored.
+    pub fn set_line(&mut self, line: usize, cells: Vec<Cell>) {
+        let mut columns = Vec::with_capacity(cells.len());
+        for cell in cells {
+            columns.push(cell);
+            if cell.width == 2 {
+                columns.push(Cell::continuation(cell.style));
+            }
+        }
+
+        if columns.get(self.width).is_some_and(Cell::is_continuation) {
+            let cut = &mut columns[self.width - 1];
+            *cut = Cell::new(' ', cut.style);
+        }
+        columns.truncate(self.width);
+
+        if let Some(l) = self.lines.get_mut(line) {
+            *l = columns;
+        }
+    }
+
+    /// Write the plain text, in `style`, as a line.
+    pub fn set_text(&mut self, line: usize, text: &str, style: Style) {
+        let cells = text.chars().map(|c| Cell::new(c, style)).collect();
+        self.set_line(line, cells);
+    }
+
+    /// Write what it takes to turn `previous` into this frame on the
+    /// terminal, with the frame's first line on screen line `top`. Without
+    /// a previous frame of the same size, every line is drawn in full.
+    pub fn draw<W: Write>(&self, top: u16, previous: Option<&Frame>, ansi: &mut ANSI<W>) {
+        let previous = previous.filter(|p| p.width == self.width && p.lines.len() == self.lines.len());
+
+        for (i, line) in self.lines.iter().enumerate() {
+            let line_number = top + i as u16;
+            match previous {
+                Some(p) => draw_line_changes(line_number, &p.lines[i], line, ansi),
+                None => {
+                    ansi.setpos(line_number, 0);
+                    draw_cells(line, ansi);
+                    ansi.clear_line();
+                },
+            }
+        }
+    }
+}
+
+/// Redraw `new` over `old` from the first cell that differs, erasing
+/// whatever is left of the old line.
+fn draw_line_changes<W: Write>(line: u16, old: &[Cell], new: &[Cell], ansi: &mut ANSI<W>) {
+    let mut first_change = old.iter().zip(new.iter()).take_while(|&(a, b)| a == b).count();
+
+    if first_change == old.len() && first_change == new.len() {
+        return;
+    }
+
+    // A change can't start halfway through a double-width char.
+    if new.get(first_change).is_some_and(Cell::is_continuation) {
+        first_change -= 1;
+    }
+
+    // Find the end of the changes, so an edit near the start of a line
+    // doesn't rewrite the unchanged rest of it.
+    let last_change = if old.len() == new.len() {
+        new.len() - old.iter().rev().zip(new.iter().rev()).take_while(|&(a, b)| a == b).count()
+    } else {
+        new.len()
+    };
+
+    ansi.setpos(line, first_change as u16);
+    draw_cells(&new[first_change..last_change], ansi);
+
+    if new.len() < old.len() {
+        ansi.clear_line();
+    }
+}
+
+/// Write the cells at the cursor, leaving the style reset after them.
+fn draw_cells<W: Write>(cells: &[Cell], ansi: &mut ANSI<W>) {
+    let mut current = Style::default();
+    for cell in cells.iter().filter(|c| !c.is_continuation()) {
+        if cell.style != current {
+            ansi.reset();
+            ansi.style(&cell.style);
+            current = cell.style;
+        }
+        ansi.write(cell.ch.to_string());
+    }
+
+    if current != Style::default() {
+        ansi.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn bold() -> Style {
+        Style { bold: true, ..Style::default() }
+    }
+
+    fn frame(lines: &[&str]) -> Frame {
+        let mut frame = Frame::new(10, lines.len() as u16);
+        for (i, line) in lines.iter().enumerate() {
+            frame.set_text(i, line, Style::default());
+        }
+        frame
+    }
+
+    fn draw(frame: &Frame, previous: Option<&Frame>) -> String {
+        let mut ansi = ANSI::new(Vec::new());
+        frame.draw(0, previous, &mut ansi);
+        String::from_utf8(ansi.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn test_draws_everything_without_a_previous_frame() {
+        let f = frame(&["> a", "abc"]);
+        assert_eq!(draw(&f, None), "\x1b[1;1H> a\x1b[K\x1b[2;1Habc\x1b[K");
+    }
+
+    #[test]
+    fn test_draws_from_the_top_line() {
+        let f = frame(&["> a", "abc"]);
+        let mut ansi = ANSI::new(Vec::new());
+        f.draw(5, Some(&frame(&["> a", "abd"])), &mut ansi);
+        assert_eq!(String::from_utf8(ansi.into_inner()).unwrap(), "\x1b[7;3Hc");
+    }
+
+    #[test]
+    fn test_draws_nothing_when_unchanged() {
+        let f = frame(&["> a", "abc"]);
+        assert_eq!(draw(&f, Some(&frame(&["> a", "abc"]))), "");
+    }
+
+    #[test]
+    fn test_draws_only_changed_cells() {
+        let f = frame(&["> ab", "abc", "xyz"]);
+        assert_eq!(draw(&f, Some(&frame(&["> a", "abc", "xyz"]))), "\x1b[1;4Hb");
+        assert_eq!(draw(&f, Some(&frame(&["> ab", "aXc", "xyz"]))), "\x1b[2;2Hb");
+    }
+
+    #[test]
+    fn test_erases_the_rest_of_shorter_lines() {
+        let f = frame(&["> a", ""]);
+        assert_eq!(draw(&f, Some(&frame(&["> ab", "abc"]))), "\x1b[1;4H\x1b[K\x1b[2;1H\x1b[K");
+    }
+
+    #[test]
+    fn test_redraws_restyled_cells() {
+        let old = frame(&["abc"]);
+        let mut new = frame(&["abc"]);
+        new.set_line(0, vec!(Cell::new('a', Style::default()),
+                             Cell::new('b', bold()),
+                             Cell::new('c', Style::default())));
+        assert_eq!(draw(&new, Some(&old)), "\x1b[1;2H\x1b[0m\x1b[1mb\x1b[0m");
+    }
+
+    #[test]
+    fn test_redraws_everything_after_a_resize() {
+        let old = frame(&["abc"]);
+        let new = frame(&["abc", ""]);
+        assert_eq!(draw(&new, Some(&old)), "\x1b[1;1Habc\x1b[K\x1b[2;1H\x1b[K");
+    }
+
+    #[test]
+    fn test_truncates_lines_to_the_width() {
+        let mut f = Frame::new(3, 1);
+        f.set_text(0, "abcdef", Style::default());
+        assert_eq!(draw(&f, None), "\x1b[1;1Habc\x1b[K");
+    }
+
+    #[test]
+    fn test_positions_changes_by_column() {
+        let f = frame(&["日本x"]);
+        assert_eq!(draw(&f, Some(&frame(&["日本語"]))), "\x1b[1;5Hx\x1b[K");
+        assert_eq!(draw(&frame(&["日本語"]), Some(&f)), "\x1b[1;5H語");
+    }
+
+    #[test]
+    fn test_redraws_whole_wide_chars() {
+        let f = frame(&["a日b"]);
+        assert_eq!(draw(&f, Some(&frame(&["a本b"]))), "\x1b[1;2H日");
+        assert_eq!(draw(&f, Some(&frame(&["axyb"]))), "\x1b[1;2H日");
+    }
+
+    #[test]
+    fn test_truncates_lines_by_width() {
+        let mut f = Frame::new(5, 1);
+        f.set_text(0, "日本語", Style::default());
+        assert_eq!(draw(&f, None), "\x1b[1;1H日本 \x1b[K");
+    }
+
+    #[test]
+    fn test_replaces_control_chars() {
+        let f = frame(&["a\tb\x1b[2J"]);
+        assert_eq!(draw(&f, None), "\x1b[1;1Ha?b?[2J\x1b[K");
+    }
+
+    #[test]
+    fn test_text_width() {
+        assert_eq!(text_width("abc"), 3);
+        assert_eq!(text_width("日本語"), 6);
+        assert_eq!(text_width("ｘa"), 3);
+    }
+}
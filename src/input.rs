@@ -0,0 +1,105 @@
+use std::io::{BufRead, BufReader, Read};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+
+/// Reads choices on a background thread, so the picker can start before
+/// the input is complete, e.g. for `find / | selecta`.
+pub struct ChoiceReader {
+    receiver: Receiver<String>,
+    done: bool,
+}
+
+impl ChoiceReader {
+    /// Start reading lines from `input` until it ends. A line that isn't
+    /// valid UTF-8 is read with the invalid bytes replaced.
+    pub fn spawn<R: Read + Send + 'static>(input: R) -> ChoiceReader {
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for line in lines(BufReader::new(input)) {
+                if sender.send(line).is_err() {
+                    // nobody is listening anymore
+                    break;
+                }
+            }
+        });
+
+        ChoiceReader { receiver, done: false }
+    }
+
+    /// The lines read since the last call.
+    pub fn take_lines(&mut self) -> Vec<String> {
+        let mut lines = Vec::new();
+        loop {
+            match self.receiver.try_recv() {
+                Ok(line) => lines.push(line),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.done = true;
+                    break;
+                },
+            }
+        }
+        lines
+    }
+
+    /// Whether the input has ended and every line has been taken.
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+}
+
+/// The lines of `input`, until it ends or can't be read. A line that
+/// isn't valid UTF-8 is read with the invalid bytes replaced, so odd file
+/// names don't stop us.
+pub fn lines<R: BufRead>(mut input: R) -> impl Iterator<Item = String> {
+    let mut buf = Vec::new();
+    std::iter::from_fn(move || {
+        buf.clear();
+        match input.read_until(b'\n', &mut buf) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(line_from_bytes(&buf)),
+        }
+    })
+}
+
+/// A line without its line ending, like `BufRead::lines` gives.
+fn line_from_bytes(bytes: &[u8]) -> String {
+    let bytes = bytes.strip_suffix(b"\n").unwrap_or(bytes);
+    let bytes = bytes.strip_suffix(b"\r").unwrap_or(bytes);
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::io::Cursor;
+
+    fn read_all(input: &'static [u8]) -> Vec<String> {
+        let mut reader = ChoiceReader::spawn(Cursor::new(input));
+        let mut lines = Vec::new();
+        while !reader.is_done() {
+            lines.extend(reader.take_lines());
+            thread::yield_now();
+        }
+        lines
+    }
+
+    #[test]
+    fn test_reads_every_line() {
+        assert!(read_all(b"one\ntwo\r\nthree") == vec!("one", "two", "three"));
+        assert!(read_all(b"").is_empty());
+    }
+
+    #[test]
+    fn test_lines_read_invalid_utf8_lossily() {
+        let read: Vec<String> = lines(Cursor::new(&b"abc\n\xff\xfeab\n"[..])).collect();
+        assert!(read == vec!("abc", "\u{fffd}\u{fffd}ab"));
+    }
+
+    #[test]
+    fn test_reads_invalid_utf8_lossily() {
+        assert!(read_all(b"a\xffb\n") == vec!("a\u{fffd}b"));
+    }
+}
@@ -0,0 +1,512 @@
+use std::cmp::min;
+use std::ops::BitOr;
+use std::str;
+
+/// Modifier keys held down with a key, as a set of bits.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Modifiers(u8);
+
+impl Modifiers {
+    pub const NONE: Modifiers = Modifiers(0);
+    pub const SHIFT: Modifiers = Modifiers(1);
+    pub const ALT: Modifiers = Modifiers(2);
+    pub const CTRL: Modifiers = Modifiers(4);
+
+    pub fn contains(self, other: Modifiers) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Decode the modifier parameter of a CSI sequence, e.g. the `5` in
+    /// `ESC [ 1 ; 5 A` (Ctrl-Up). xterm encodes it as 1 + the bit set.
+    fn from_csi_param(param: u16) -> Modifiers {
+        Modifiers((param.saturating_sub(1) & 0x7) as u8)
+    }
+}
+
+impl BitOr for Modifiers {
+    type Output = Modifiers;
+
+    fn bitor(self, other: Modifiers) -> Modifiers {
+        Modifiers(self.0 | other.0)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Key {
+    Char(char),
+    Enter,
+    Tab,
+    Backspace,
+    Delete,
+    Insert,
+    Escape,
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    F(u8),
+}
+
+/// A key along with the modifiers held down when it was pressed.
+///
+/// Control characters come through as `Key::Char` with `Modifiers::CTRL`,
+/// so Ctrl-N is `KeyEvent::ctrl('n')`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct KeyEvent {
+    pub key: Key,
+    pub modifiers: Modifiers,
+}
+
+impl KeyEvent {
+    pub fn new(key: Key, modifiers: Modifiers) -> KeyEvent {
+        KeyEvent { key, modifiers }
+    }
+
+    pub fn plain(key: Key) -> KeyEvent {
+        KeyEvent::new(key, Modifiers::NONE)
+    }
+
+    pub fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(Key::Char(c), Modifiers::CTRL)
+    }
+
+    pub fn alt(c: char) -> KeyEvent {
+        KeyEvent::new(Key::Char(c), Modifiers::ALT)
+    }
+
+    fn with(self, modifiers: Modifiers) -> KeyEvent {
+        KeyEvent::new(self.key, self.modifiers | modifiers)
+    }
+}
+
+const ESC: u8 = 0x1b;
+
+/// The result of trying to decode the front of the input buffer.
+#[derive(Debug, PartialEq)]
+enum Decoded {
+    /// A key, and how many bytes it used.
+    Key(KeyEvent, usize),
+    /// A sequence we don't understand, and how many bytes to drop.
+    Unknown(usize),
+    /// The buffer ends partway through a sequence.
+    Incomplete,
+}
+
+/// Turns the bytes read from the terminal into key events.
+///
+/// A lone ESC is ambiguous: it is the Escape key, but also the start of
+/// every escape sequence. `next_key` waits for more bytes in that case,
+/// and the caller calls `flush` once no more input arrives within a short
+/// timeout, at which point whatever is buffered is taken literally.
+pub struct Decoder {
+    buf: Vec<u8>,
+}
+
+impl Decoder {
+    pub fn new() -> Decoder {
+        Decoder { buf: Vec::new() }
+    }
+
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Whether bytes of an unfinished sequence are waiting for more input.
+    pub fn has_pending(&self) -> bool {
+        !self.buf.is_empty()
+    }
+
+    /// Decode the next complete key, if the buffer holds one.
+    pub fn next_key(&mut self) -> Option<KeyEvent> {
+        loop {
+            match decode(&self.buf) {
+                Decoded::Key(key, len) => {
+                    self.buf.drain(..len);
+                    return Some(key);
+                },
+                Decoded::Unknown(len) => {
+                    self.buf.drain(..len);
+                },
+                Decoded::Incomplete => return None,
+            }
+        }
+    }
+
+    /// Give up waiting for the rest of a sequence and decode what we have:
+    /// a lone ESC is the Escape key and ESC followed by a byte is that key
+    /// with Alt.
+    pub fn flush(&mut self) -> Option<KeyEvent> {
+        if let Some(key) = self.next_key() {
+            return Some(key);
+        }
+
+        if self.buf.is_empty() {
+            return None;
+        }
+
+        match decode_partial(&self.buf) {
+            Some((key, len)) => {
+                self.buf.drain(..len);
+                Some(key)
+            },
+            None => {
+                self.buf.clear();
+                None
+            },
+        }
+    }
+}
+
+impl Default for Decoder {
+    fn default() -> Decoder {
+        Decoder::new()
+    }
+}
+
+fn decode(buf: &[u8]) -> Decoded {
+    match buf {
+        [] => Decoded::Incomplete,
+        [ESC] => Decoded::Incomplete,
+        [ESC, b'[', rest @ ..] => decode_csi(rest).offset(2),
+        [ESC, b'O', rest @ ..] => decode_ss3(rest).offset(2),
+        [ESC, rest @ ..] => match decode(rest) {
+            Decoded::Key(key, len) => Decoded::Key(key.with(Modifiers::ALT), len + 1),
+            other => other.offset(1),
+        },
+        [byte, ..] if *byte >= 0x80 => decode_utf8(buf),
+        [byte, ..] => Decoded::Key(decode_byte(*byte), 1),
+    }
+}
+
+/// Decode a multibyte UTF-8 character. Bytes that can't start or continue
+/// a valid sequence are dropped one at a time.
+fn decode_utf8(buf: &[u8]) -> Decoded {
+    let len = match buf[0] {
+        0xc2..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf4 => 4,
+        _ => return Decoded::Unknown(1),
+    };
+
+    let available = &buf[..min(len, buf.len())];
+    let continuations_ok = available[1..].iter().all(|&b| b & 0xc0 == 0x80);
+
+    if !continuations_ok {
+        return Decoded::Unknown(1);
+    }
+
+    if available.len() < len {
+        return Decoded::Incomplete;
+    }
+
+    match str::from_utf8(available) {
+        Ok(s) => Decoded::Key(KeyEvent::plain(Key::Char(s.chars().next().unwrap())), len),
+        // overlong encodings and surrogates
+        Err(_) => Decoded::Unknown(1),
+    }
+}
+
+/// Decode a single ASCII byte that isn't ESC.
+fn decode_byte(byte: u8) -> KeyEvent {
+    match byte {
+        b'\r' | b'\n' => KeyEvent::plain(Key::Enter),
+        b'\t' => KeyEvent::plain(Key::Tab),
+        0x7f | 0x08 => KeyEvent::plain(Key::Backspace),
+        0x00 => KeyEvent::ctrl(' '),
+        0x01..=0x1a => KeyEvent::ctrl((b'a' + byte - 1) as char),
+        0x1c..=0x1f => KeyEvent::ctrl((b'\\' + byte - 0x1c) as char),
+        _ => KeyEvent::plain(Key::Char(byte as char)),
+    }
+}
+
+/// Decode the body of a CSI sequence, after `ESC [`: numeric parameters
+/// separated by `;`, then a final byte.
+fn decode_csi(buf: &[u8]) -> Decoded {
+    let end = match buf.iter().position(|&b| (0x40..=0x7e).contains(&b)) {
+        Some(end) => end,
+        None if buf.iter().all(|&b| (0x20..=0x3f).contains(&b)) => return Decoded::Incomplete,
+        // something that isn't a CSI parameter byte; drop the introducer
+        None => return Decoded::Unknown(0),
+    };
+
+    let params: Vec<u16> = buf[..end].split(|&b| b == b';').map(|p|
+        str::from_utf8(p).ok().and_then(|p| p.parse().ok()).unwrap_or(0)
+    ).collect();
+    let modifiers = Modifiers::from_csi_param(params.get(1).cloned().unwrap_or(1));
+
+    let key = match buf[end] {
+        b'~' => match params[0] {
+            1 | 7 => Some(Key::Home),
+            2 => Some(Key::Insert),
+            3 => Some(Key::Delete),
+            4 | 8 => Some(Key::End),
+            5 => Some(Key::PageUp),
+            6 => Some(Key::PageDown),
+            n @ 11..=15 => Some(Key::F((n - 10) as u8)),
+            n @ 17..=21 => Some(Key::F((n - 11) as u8)),
+            n @ 23..=24 => Some(Key::F((n - 12) as u8)),
+            _ => None,
+        },
+        b'Z' => return Decoded::Key(KeyEvent::new(Key::Tab, Modifiers::SHIFT), end + 1),
+        other => final_byte_key(other),
+    };
+
+    match key {
+        Some(key) => Decoded::Key(KeyEvent::new(key, modifiers), end + 1),
+        None => Decoded::Unknown(end + 1),
+    }
+}
+
+/// Decode the body of an SS3 sequence, after `ESC O`.
+fn decode_ss3(buf: &[u8]) -> Decoded {
+    match buf.first() {
+        None => Decoded::Incomplete,
+        Some(&byte) => match final_byte_key(byte) {
+            Some(key) => Decoded::Key(KeyEvent::plain(key), 1),
+            None => Decoded::Unknown(1),
+        },
+    }
+}
+
+/// Keys identified by the final byte of a CSI or SS3 sequence alone.
+fn final_byte_key(byte: u8) -> Option<Key> {
+    match byte {
+        b'A' => Some(Key::Up),
+        b'B' => Some(Key::Down),
+        b'C' => Some(Key::Right),
+        b'D' => Some(Key::Left),
+        b'H' => Some(Key::Home),
+        b'F' => Some(Key::End),
+        b'P' => Some(Key::F(1)),
+        b'Q' => Some(Key::F(2)),
+        b'R' => Some(Key::F(3)),
+        b'S' => Some(Key::F(4)),
+        _ => None,
+    }
+}
+
+/// Decode an incomplete sequence once we've given up waiting for the rest.
+/// A truncated UTF-8 character decodes to nothing.
+fn decode_partial(buf: &[u8]) -> Option<(KeyEvent, usize)> {
+    match buf {
+        [ESC, ESC, ..] => Some((KeyEvent::new(Key::Escape, Modifiers::ALT), 2)),
+        [ESC, byte, ..] if byte.is_ascii() => Some((decode_byte(*byte).with(Modifiers::ALT), 2)),
+        [ESC, ..] => Some((KeyEvent::plain(Key::Escape), 1)),
+        _ => None,
+    }
+}
+
+impl Decoded {
+    /// Account for `n` bytes of prefix consumed before this decode.
+    fn offset(self, n: usize) -> Decoded {
+        match self {
+            Decoded::Key(key, len) => Decoded::Key(key, len + n),
+            Decoded::Unknown(len) => Decoded::Unknown(len + n),
+            Decoded::Incomplete => Decoded::Incomplete,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn decode_all(bytes: &[u8]) -> Vec<KeyEvent> {
+        let mut decoder = Decoder::new();
+        decoder.push(bytes);
+
+        let mut keys = Vec::new();
+        while let Some(key) = decoder.next_key() {
+            keys.push(key);
+        }
+        if let Some(key) = decoder.flush() {
+            keys.push(key);
+        }
+        keys
+    }
+
+    fn decode_one(bytes: &[u8]) -> KeyEvent {
+        let keys = decode_all(bytes);
+        assert!(keys.len() == 1, "expected one key from {:?}, got {:?}", bytes, keys);
+        keys[0]
+    }
+
+    #[test]
+    fn test_decodes_printable_characters() {
+        assert!(decode_all(b"ab") == vec!(KeyEvent::plain(Key::Char('a')),
+                                          KeyEvent::plain(Key::Char('b'))));
+    }
+
+    #[test]
+    fn test_decodes_control_characters() {
+        assert!(decode_one(b"\x0e") == KeyEvent::ctrl('n'));
+        assert!(decode_one(b"\x03") == KeyEvent::ctrl('c'));
+        assert!(decode_one(b"\x17") == KeyEvent::ctrl('w'));
+        assert!(decode_one(b"\x00") == KeyEvent::ctrl(' '));
+        assert!(decode_one(b"\x1f") == KeyEvent::ctrl('_'));
+    }
+
+    #[test]
+    fn test_decodes_special_single_bytes() {
+        assert!(decode_one(b"\r") == KeyEvent::plain(Key::Enter));
+        assert!(decode_one(b"\n") == KeyEvent::plain(Key::Enter));
+        assert!(decode_one(b"\t") == KeyEvent::plain(Key::Tab));
+        assert!(decode_one(b"\x7f") == KeyEvent::plain(Key::Backspace));
+        assert!(decode_one(b"\x08") == KeyEvent::plain(Key::Backspace));
+    }
+
+    #[test]
+    fn test_decodes_arrow_keys() {
+        assert!(decode_one(b"\x1b[A") == KeyEvent::plain(Key::Up));
+        assert!(decode_one(b"\x1b[B") == KeyEvent::plain(Key::Down));
+        assert!(decode_one(b"\x1b[C") == KeyEvent::plain(Key::Right));
+        assert!(decode_one(b"\x1b[D") == KeyEvent::plain(Key::Left));
+        // application cursor mode
+        assert!(decode_one(b"\x1bOA") == KeyEvent::plain(Key::Up));
+        assert!(decode_one(b"\x1bOD") == KeyEvent::plain(Key::Left));
+    }
+
+    #[test]
+    fn test_decodes_navigation_keys() {
+        assert!(decode_one(b"\x1b[H") == KeyEvent::plain(Key::Home));
+        assert!(decode_one(b"\x1b[F") == KeyEvent::plain(Key::End));
+        assert!(decode_one(b"\x1b[1~") == KeyEvent::plain(Key::Home));
+        assert!(decode_one(b"\x1b[4~") == KeyEvent::plain(Key::End));
+        assert!(decode_one(b"\x1bOH") == KeyEvent::plain(Key::Home));
+        assert!(decode_one(b"\x1b[5~") == KeyEvent::plain(Key::PageUp));
+        assert!(decode_one(b"\x1b[6~") == KeyEvent::plain(Key::PageDown));
+        assert!(decode_one(b"\x1b[2~") == KeyEvent::plain(Key::Insert));
+        assert!(decode_one(b"\x1b[3~") == KeyEvent::plain(Key::Delete));
+    }
+
+    #[test]
+    fn test_decodes_function_keys() {
+        assert!(decode_one(b"\x1bOP") == KeyEvent::plain(Key::F(1)));
+        assert!(decode_one(b"\x1bOS") == KeyEvent::plain(Key::F(4)));
+        assert!(decode_one(b"\x1b[15~") == KeyEvent::plain(Key::F(5)));
+        assert!(decode_one(b"\x1b[21~") == KeyEvent::plain(Key::F(10)));
+        assert!(decode_one(b"\x1b[24~") == KeyEvent::plain(Key::F(12)));
+    }
+
+    #[test]
+    fn test_decodes_modified_sequences() {
+        assert!(decode_one(b"\x1b[1;5C") == KeyEvent::new(Key::Right, Modifiers::CTRL));
+        assert!(decode_one(b"\x1b[1;3D") == KeyEvent::new(Key::Left, Modifiers::ALT));
+        assert!(decode_one(b"\x1b[1;2A") == KeyEvent::new(Key::Up, Modifiers::SHIFT));
+        assert!(decode_one(b"\x1b[3;5~") == KeyEvent::new(Key::Delete, Modifiers::CTRL));
+        assert!(decode_one(b"\x1b[1;7B") == KeyEvent::new(Key::Down, Modifiers::CTRL | Modifiers::ALT));
+        assert!(decode_one(b"\x1b[Z") == KeyEvent::new(Key::Tab, Modifiers::SHIFT));
+    }
+
+    #[test]
+    fn test_decodes_alt_combinations() {
+        assert!(decode_one(b"\x1bb") == KeyEvent::alt('b'));
+        assert!(decode_one(b"\x1bf") == KeyEvent::alt('f'));
+        assert!(decode_one(b"\x1b\x7f") == KeyEvent::new(Key::Backspace, Modifiers::ALT));
+        assert!(decode_one(b"\x1b\x1b[A") == KeyEvent::new(Key::Up, Modifiers::ALT));
+    }
+
+    #[test]
+    fn test_lone_escape_waits_for_timeout() {
+        let mut decoder = Decoder::new();
+        decoder.push(b"\x1b");
+        assert!(decoder.next_key().is_none());
+        assert!(decoder.has_pending());
+        assert!(decoder.flush() == Some(KeyEvent::plain(Key::Escape)));
+        assert!(!decoder.has_pending());
+    }
+
+    #[test]
+    fn test_sequence_split_across_reads() {
+        let mut decoder = Decoder::new();
+        decoder.push(b"\x1b[");
+        assert!(decoder.next_key().is_none());
+        decoder.push(b"1;5");
+        assert!(decoder.next_key().is_none());
+        decoder.push(b"Cx");
+        assert!(decoder.next_key() == Some(KeyEvent::new(Key::Right, Modifiers::CTRL)));
+        assert!(decoder.next_key() == Some(KeyEvent::plain(Key::Char('x'))));
+        assert!(decoder.next_key().is_none());
+    }
+
+    #[test]
+    fn test_flushes_escape_bracket_as_alt_bracket() {
+        let mut decoder = Decoder::new();
+        decoder.push(b"\x1b[");
+        assert!(decoder.next_key().is_none());
+        assert!(decoder.flush() == Some(KeyEvent::alt('[')));
+    }
+
+    #[test]
+    fn test_skips_unknown_sequences() {
+        assert!(decode_all(b"\x1b[99~a") == vec!(KeyEvent::plain(Key::Char('a'))));
+        assert!(decode_all(b"\x1b[?1;2cq") == vec!(KeyEvent::plain(Key::Char('q'))));
+    }
+
+    #[test]
+    fn test_decodes_several_keys_in_one_read() {
+        assert!(decode_all(b"a\x1b[Bb\r") == vec!(KeyEvent::plain(Key::Char('a')),
+                                                  KeyEvent::plain(Key::Down),
+                                                  KeyEvent::plain(Key::Char('b')),
+                                                  KeyEvent::plain(Key::Enter)));
+    }
+
+    #[test]
+    fn test_decodes_multibyte_characters() {
+        assert!(decode_one("é".as_bytes()) == KeyEvent::plain(Key::Char('é')));
+        assert!(decode_one("日".as_bytes()) == KeyEvent::plain(Key::Char('日')));
+        assert!(decode_one("🦀".as_bytes()) == KeyEvent::plain(Key::Char('🦀')));
+        assert!(decode_all("aü日".as_bytes()) == vec!(KeyEvent::plain(Key::Char('a')),
+                                                     KeyEvent::plain(Key::Char('ü')),
+                                                     KeyEvent::plain(Key::Char('日'))));
+    }
+
+    #[test]
+    fn test_multibyte_character_split_across_reads() {
+        let bytes = "日".as_bytes();
+        let mut decoder = Decoder::new();
+        decoder.push(&bytes[..1]);
+        assert!(decoder.next_key().is_none());
+        decoder.push(&bytes[1..2]);
+        assert!(decoder.next_key().is_none());
+        decoder.push(&bytes[2..]);
+        assert!(decoder.next_key() == Some(KeyEvent::plain(Key::Char('日'))));
+    }
+
+    #[test]
+    fn test_decodes_alt_with_multibyte_character() {
+        assert!(decode_one("\x1bé".as_bytes()) == KeyEvent::new(Key::Char('é'), Modifiers::ALT));
+    }
+
+    #[test]
+    fn test_skips_invalid_utf8() {
+        // stray continuation byte, bytes that never start a sequence
+        assert!(decode_all(b"\x80a\xffb\xc0\xafc") == vec!(KeyEvent::plain(Key::Char('a')),
+                                                           KeyEvent::plain(Key::Char('b')),
+                                                           KeyEvent::plain(Key::Char('c'))));
+        // a lead byte followed by something that isn't a continuation
+        assert!(decode_all(b"\xc3a") == vec!(KeyEvent::plain(Key::Char('a'))));
+        // an encoded surrogate
+        assert!(decode_all(b"\xed\xa0\x80z") == vec!(KeyEvent::plain(Key::Char('z'))));
+    }
+
+    #[test]
+    fn test_drops_truncated_character_on_flush() {
+        let mut decoder = Decoder::new();
+        decoder.push(&"é".as_bytes()[..1]);
+        assert!(decoder.next_key().is_none());
+        assert!(decoder.flush().is_none());
+        assert!(!decoder.has_pending());
+
+        let mut decoder = Decoder::new();
+        decoder.push(b"\x1b\xc3");
+        assert!(decoder.flush() == Some(KeyEvent::plain(Key::Escape)));
+        assert!(decoder.flush().is_none());
+        assert!(!decoder.has_pending());
+    }
+}
@@ -0,0 +1,13 @@
+pub mod winsize;
+pub mod tty;
+pub mod ansi;
+pub mod screen;
+pub mod config;
+pub mod frame;
+pub mod input;
+pub mod key;
+pub mod options;
+pub mod query;
+pub mod score;
+pub mod search;
+pub mod signals;
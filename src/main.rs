@@ -1,61 +1,204 @@
-#![allow(unstable)]
+use std::env;
+use std::fmt::Display;
+use std::io::{self, stdin, stdout, BufWriter, Write};
+use std::process;
+use std::time::Duration;
+
+use selecta::config::Configuration;
+use selecta::input::{self, ChoiceReader};
+use selecta::key::{Key, KeyEvent, Modifiers};
+use selecta::options::{self, Command, Options};
+use selecta::query::Query;
+use selecta::screen::{Screen, ScreenGuard};
+use selecta::search::{self, Search};
+use selecta::signals;
+
+/// How often to take in new choices while they're being read.
+const READ_INTERVAL: Duration = Duration::from_millis(50);
 
-#![feature(plugin)]
-#[plugin] #[no_link] extern crate regex_macros;
-extern crate regex;
-
-extern crate libc;
-extern crate test;
+fn main() {
+    let options = match options::parse_args(env::args().skip(1)) {
+        Ok(Command::Run(options)) => options,
+        Ok(Command::Help) => {
+            println!("{}", options::USAGE);
+            return;
+        },
+        Ok(Command::Version) => {
+            println!("selecta {}", env!("CARGO_PKG_VERSION"));
+            return;
+        },
+        Err(e) => {
+            eprintln!("selecta: {}", e);
+            eprintln!("{}", options::USAGE);
+            process::exit(2);
+        },
+    };
+
+    match options.filter {
+        Some(ref query) => filter(query, &options),
+        None => {
+            let result = run(options);
+
+            // the terminal is restored by now; die from a SIGINT, SIGTERM
+            // or SIGHUP if that's why we stopped
+            signals::reraise_termination();
+
+            match result {
+                Ok(selections) if !selections.is_empty() => print_lines(selections),
+                Ok(_) => process::exit(1),
+                Err(e) => {
+                    eprintln!("selecta: {}", e);
+                    process::exit(1);
+                },
+            }
+        },
+    }
+}
 
-use std::io::stdin;
+/// Print every choice matching `query`, best match first, without
+/// touching the terminal.
+fn filter(query: &str, options: &Options) {
+    let choices = stdin_readlines();
 
-//mod winsize;
-//mod tty;
-//mod ansi;
-//mod screen;
-mod config;
-mod score;
-mod search;
+    let query = Query::new(query, options.extended);
+    let matches = search::compute_matches(&choices, &query, options.algorithm.scorer());
 
+    if matches.is_empty() {
+        process::exit(1);
+    }
 
-#[cfg(not(test))]
-fn main() {
+    print_lines(matches);
+}
 
-    //let mut screen = screen::Screen::with_screen();
-    //let mut _choices = stdin_readlines();
+/// Write the lines to stdout. A reader that goes away early, like `head`,
+/// isn't an error: we just stop writing.
+fn print_lines<I>(lines: I) where I: IntoIterator, I::Item: Display {
+    let stdout = stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    let written = lines.into_iter()
+        .try_for_each(|line| writeln!(out, "{}", line))
+        .and_then(|_| out.flush());
+
+    match written {
+        Err(ref e) if e.kind() == io::ErrorKind::BrokenPipe => {},
+        Err(e) => {
+            eprintln!("selecta: {}", e);
+            process::exit(1);
+        },
+        Ok(()) => {},
+    }
+}
 
-    let score = score::score("a", "b");
-    println!("score = {}", score);
+/// Run the interactive picker and return the chosen lines, if any.
+fn run(options: Options) -> io::Result<Vec<String>> {
+    let mut input = ChoiceReader::spawn(stdin());
 
-    //let config = config::Configuration::from_inputs(choices, screen.height());
+    let mut screen = ScreenGuard::new(Screen::with_screen()?)?;
+    screen.set_styles(options.match_style, options.selection_style);
+    let config = Configuration::from_inputs(Vec::new(), &options, screen.height()?);
+    screen.start(options.layout, config.get_visible_choices() as u16 + 1)?;
 
-    //s.configure_tty();
-    //s.move_cursor(0, 5);
-    //s.restore_tty();
+    signals::install_resize_handler()?;
+    let search = run_in_screen(config, &mut input, &mut screen)?;
 
-    //let width = s.width();
-    //let height = s.height();
-    //println!("Window size is {} x {}", width, height);
+    Ok(search.map(|s| s.selections().into_iter().cloned().collect()).unwrap_or_default())
+}
 
-    //let mut tty = match tty::TTY::new() {
-    //    Ok(tty) => tty,
-    //    Err(r) => {
-    //        println!("Failed to open a tty: {}", r);
-    //        return;
-    //    }
-    //};
-    //println!("I read in {}", tty.get_char());
+/// Run the key loop until the user picks a choice or aborts.
+///
+/// Returns `None` when the user aborts with Escape, or we're asked to
+/// terminate, as Ctrl-C does with SIGINT.
+fn run_in_screen(config: Configuration,
+                 input: &mut ChoiceReader,
+                 screen: &mut Screen) -> io::Result<Option<Search>> {
+    let mut search = Search::blank(config);
+    let multi = search.is_multi();
+
+    loop {
+        if signals::termination_requested() {
+            return Ok(None);
+        }
+
+        if !input.is_done() {
+            search = search.add_choices(input.take_lines());
+            screen.set_reading(!input.is_done());
+        }
+
+        if signals::take_resized() {
+            search = search.resize(screen.height()?);
+            screen.resize(search.get_visible_choices() as u16 + 1)?;
+        }
+
+        screen.render(&search)?;
+
+        // while choices are coming in, stop waiting now and then to show them
+        let timeout = if input.is_done() { None } else { Some(READ_INTERVAL) };
+
+        let key = match screen.get_key_timeout(timeout) {
+            Ok(Some(key)) => key,
+            Ok(None) => continue,
+            // interrupted by a signal; go around to resize or stop
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+
+        search = match key {
+            KeyEvent { key: Key::Escape, .. } => { return Ok(None); },
+            KeyEvent { key: Key::Enter, .. } => { search.done(); search },
+            KeyEvent { key: Key::Tab, modifiers: Modifiers::NONE } if multi => { search.toggle_mark(); search.down() },
+            KeyEvent { key: Key::Tab, modifiers: Modifiers::SHIFT } if multi => { search.toggle_mark(); search.up() },
+            KeyEvent { key: Key::Down, .. } => search.down(),
+            KeyEvent { key: Key::Up, .. } => search.up(),
+            KeyEvent { key: Key::PageDown, .. } => search.page_down(),
+            KeyEvent { key: Key::PageUp, .. } => search.page_up(),
+            KeyEvent { key: Key::Home, .. } => search.first(),
+            KeyEvent { key: Key::End, .. } => search.last(),
+            KeyEvent { key: Key::Delete, .. } => { search.delete_forward(); search },
+            KeyEvent { key: Key::Backspace, modifiers: Modifiers::ALT } => { search.delete_word(); search },
+            KeyEvent { key: Key::Backspace, .. } => { search.backspace(); search },
+            KeyEvent { key: Key::Left, modifiers } if is_word_motion(modifiers) => { search.word_left(); search },
+            KeyEvent { key: Key::Right, modifiers } if is_word_motion(modifiers) => { search.word_right(); search },
+            KeyEvent { key: Key::Left, .. } => { search.cursor_left(); search },
+            KeyEvent { key: Key::Right, .. } => { search.cursor_right(); search },
+            KeyEvent { key: Key::Char(c), modifiers: Modifiers::CTRL } => match c {
+                'n' => search.down(),
+                'p' => search.up(),
+                'a' => { search.cursor_home(); search },
+                'e' => { search.cursor_end(); search },
+                'b' => { search.cursor_left(); search },
+                'f' => { search.cursor_right(); search },
+                'd' => { search.delete_forward(); search },
+                'k' => { search.kill_to_end(); search },
+                'y' => search.yank(),
+                'u' => { search.clear_query(); search },
+                'w' => { search.delete_word(); search },
+                _ => search,
+            },
+            KeyEvent { key: Key::Char(c), modifiers: Modifiers::ALT } => match c {
+                'b' => { search.word_left(); search },
+                'f' => { search.word_right(); search },
+                'a' if multi => { search.mark_all(); search },
+                'd' if multi => { search.unmark_all(); search },
+                _ => search,
+            },
+            KeyEvent { key: Key::Char(c), modifiers: Modifiers::NONE } if !c.is_control() => {
+                let s = c.to_string();
+                search.append_search_string(&s)
+            },
+            _ => search,
+        };
+
+        if search.is_done() {
+            return Ok(Some(search));
+        }
+    }
+}
 
-    //tty.puts();
+/// Ctrl or Alt with an arrow key moves by words.
+fn is_word_motion(modifiers: Modifiers) -> bool {
+    modifiers.contains(Modifiers::CTRL) || modifiers.contains(Modifiers::ALT)
 }
 
-#[cfg(not(test))]
 fn stdin_readlines() -> Vec<String> {
-    let mut choices: Vec<String> = Vec::new();
-
-    for line in stdin().lock().lines() {
-        choices.push(line.unwrap());
-    }
-
-    choices
+    input::lines(stdin().lock()).collect()
 }
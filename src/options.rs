@@ -0,0 +1,337 @@
+use std::fmt;
+
+use crate::ansi::Style;
+use crate::score::Algorithm;
+use crate::screen::{Layout, DEFAULT_MATCH_STYLE, DEFAULT_SELECTION_STYLE};
+
+pub const USAGE: &str = "Usage: selecta [options]
+
+Options:
+    -s, --search SEARCH    Specify an initial search string
+        --height HEIGHT    The number of lines to use, or \"full\" (default: 21,
+                           or full with --fullscreen)
+        --fullscreen       Draw on the alternate screen instead of below the cursor
+    -f, --filter QUERY     Print the choices matching QUERY, best first, without a UI
+        --no-wrap          Stop at the ends of the list instead of wrapping around
+        --no-extended      Match the query as typed, without the extended syntax
+    -m, --multi            Mark choices with Tab and print every marked one
+        --algorithm NAME   How to score matches: selecta, smith-waterman or
+                           substring (default: selecta)
+        --match-style STYLE
+                           How to show matched characters (default: bold,green)
+        --selection-style STYLE
+                           How to show the selected line (default: inverse)
+    -h, --help             Show this message
+    -v, --version          Show the version
+
+Queries use fzf's extended syntax: 'exact, ^prefix, suffix$, !negated and
+terms joined by |. A backslash makes a space or any of ! ' ^ $ | match itself.
+
+STYLE is a comma separated list of bold, underline, inverse and at most one
+color (black, red, green, yellow, blue, magenta, cyan, white), or none.";
+
+/// The number of screen lines used inline when `--height` is not given.
+const DEFAULT_HEIGHT: u16 = 21;
+
+/// How many lines of the screen the picker may use, prompt included.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Height {
+    Lines(u16),
+    Full,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    pub search: String,
+    pub height: Height,
+    pub layout: Layout,
+    /// When set, rank stdin against this query and print the matches
+    /// instead of running the interactive picker.
+    pub filter: Option<String>,
+    pub wraparound: bool,
+    pub multi: bool,
+    /// Whether queries use the extended syntax, or are matched as typed.
+    pub extended: bool,
+    pub algorithm: Algorithm,
+    pub match_style: Style,
+    pub selection_style: Style,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options { search: String::new(), height: Height::Lines(DEFAULT_HEIGHT),
+                  layout: Layout::Inline, filter: None, wraparound: true, multi: false,
+                  extended: true, algorithm: Algorithm::Selecta,
+                  match_style: DEFAULT_MATCH_STYLE,
+                  selection_style: DEFAULT_SELECTION_STYLE }
+    }
+}
+
+/// What the command line asked selecta to do.
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(Options),
+    Help,
+    Version,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum OptionsError {
+    MissingValue(String),
+    InvalidValue(String, String),
+    /// A `--flag=value` for a flag that doesn't take one.
+    UnexpectedValue(String),
+    UnknownOption(String),
+}
+
+impl fmt::Display for OptionsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            OptionsError::MissingValue(ref option) =>
+                write!(f, "missing value for {}", option),
+            OptionsError::InvalidValue(ref option, ref value) =>
+                write!(f, "invalid value for {}: '{}'", option, value),
+            OptionsError::UnexpectedValue(ref option) =>
+                write!(f, "{} takes no value", option),
+            OptionsError::UnknownOption(ref option) =>
+                write!(f, "unknown option: {}", option),
+        }
+    }
+}
+
+/// Parse the command line arguments, not including the program name.
+pub fn parse_args<I>(args: I) -> Result<Command, OptionsError>
+    where I: IntoIterator<Item = String>
+{
+    let mut options = Options::default();
+    let mut height = None;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        // Support both `--option value` and `--option=value`.
+        let (name, inline_value) = match arg.find('=') {
+            Some(i) if arg.starts_with("--") => (arg[..i].to_string(), Some(arg[i + 1..].to_string())),
+            _ => (arg.clone(), None),
+        };
+
+        match name.as_str() {
+            "-h" | "--help" => {
+                no_value(&name, inline_value)?;
+                return Ok(Command::Help);
+            },
+            "-v" | "--version" => {
+                no_value(&name, inline_value)?;
+                return Ok(Command::Version);
+            },
+            "-s" | "--search" => {
+                options.search = value_for(&name, inline_value, &mut args)?;
+            },
+            "-f" | "--filter" => {
+                options.filter = Some(value_for(&name, inline_value, &mut args)?);
+            },
+            "--no-wrap" => {
+                no_value(&name, inline_value)?;
+                options.wraparound = false;
+            },
+            "-m" | "--multi" => {
+                no_value(&name, inline_value)?;
+                options.multi = true;
+            },
+            "--no-extended" => {
+                no_value(&name, inline_value)?;
+                options.extended = false;
+            },
+            "--fullscreen" => {
+                no_value(&name, inline_value)?;
+                options.layout = Layout::Fullscreen;
+            },
+            "--match-style" => {
+                let value = value_for(&name, inline_value, &mut args)?;
+                options.match_style = parse_style(&name, &value)?;
+            },
+            "--selection-style" => {
+                let value = value_for(&name, inline_value, &mut args)?;
+                options.selection_style = parse_style(&name, &value)?;
+            },
+            "--algorithm" => {
+                let value = value_for(&name, inline_value, &mut args)?;
+                options.algorithm = Algorithm::parse(&value)
+                    .ok_or_else(|| OptionsError::InvalidValue(name.to_string(), value.to_string()))?;
+            },
+            "--height" => {
+                let value = value_for(&name, inline_value, &mut args)?;
+                height = Some(parse_height(&name, &value)?);
+            },
+            _ => return Err(OptionsError::UnknownOption(arg)),
+        }
+    }
+
+    // the alternate screen is ours alone, so fill it unless asked not to
+    options.height = match (height, options.layout) {
+        (Some(height), _) => height,
+        (None, Layout::Fullscreen) => Height::Full,
+        (None, Layout::Inline) => Height::Lines(DEFAULT_HEIGHT),
+    };
+
+    Ok(Command::Run(options))
+}
+
+fn value_for<I>(name: &str, inline_value: Option<String>, args: &mut I) -> Result<String, OptionsError>
+    where I: Iterator<Item = String>
+{
+    match inline_value {
+        Some(value) => Ok(value),
+        None => args.next().ok_or_else(|| OptionsError::MissingValue(name.to_string())),
+    }
+}
+
+fn no_value(name: &str, inline_value: Option<String>) -> Result<(), OptionsError> {
+    match inline_value {
+        Some(_) => Err(OptionsError::UnexpectedValue(name.to_string())),
+        None => Ok(()),
+    }
+}
+
+fn parse_style(name: &str, value: &str) -> Result<Style, OptionsError> {
+    Style::parse(value).ok_or_else(|| OptionsError::InvalidValue(name.to_string(), value.to_string()))
+}
+
+fn parse_height(name: &str, value: &str) -> Result<Height, OptionsError> {
+    if value == "full" {
+        return Ok(Height::Full);
+    }
+
+    // The prompt takes a line, so anything less than two shows no choices.
+    match value.parse::<u16>() {
+        Ok(lines) if lines >= 2 => Ok(Height::Lines(lines)),
+        _ => Err(OptionsError::InvalidValue(name.to_string(), value.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, OptionsError> {
+        parse_args(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn test_defaults_without_arguments() {
+        assert_eq!(parse(&[]), Ok(Command::Run(Options::default())));
+    }
+
+    #[test]
+    fn test_parses_initial_search() {
+        let expected = Options { search: "foo".to_string(), ..Options::default() };
+        assert_eq!(parse(&["--search", "foo"]), Ok(Command::Run(expected)));
+
+        let expected = Options { search: "bar".to_string(), ..Options::default() };
+        assert_eq!(parse(&["-s", "bar"]), Ok(Command::Run(expected)));
+
+        let expected = Options { search: "baz".to_string(), ..Options::default() };
+        assert_eq!(parse(&["--search=baz"]), Ok(Command::Run(expected)));
+    }
+
+    #[test]
+    fn test_parses_height() {
+        let expected = Options { height: Height::Lines(10), ..Options::default() };
+        assert_eq!(parse(&["--height", "10"]), Ok(Command::Run(expected)));
+
+        let expected = Options { height: Height::Full, ..Options::default() };
+        assert_eq!(parse(&["--height=full"]), Ok(Command::Run(expected)));
+    }
+
+    #[test]
+    fn test_parses_multi() {
+        let expected = Options { multi: true, ..Options::default() };
+        assert_eq!(parse(&["--multi"]), Ok(Command::Run(expected)));
+
+        let expected = Options { multi: true, ..Options::default() };
+        assert_eq!(parse(&["-m"]), Ok(Command::Run(expected)));
+    }
+
+    #[test]
+    fn test_parses_algorithm() {
+        let expected = Options { algorithm: Algorithm::SmithWaterman, ..Options::default() };
+        assert_eq!(parse(&["--algorithm", "smith-waterman"]), Ok(Command::Run(expected)));
+
+        let expected = Options { algorithm: Algorithm::Substring, ..Options::default() };
+        assert_eq!(parse(&["--algorithm=substring"]), Ok(Command::Run(expected)));
+
+        assert_eq!(parse(&["--algorithm", "fast"]),
+                   Err(OptionsError::InvalidValue("--algorithm".to_string(), "fast".to_string())));
+    }
+
+    #[test]
+    fn test_parses_fullscreen() {
+        let expected = Options { layout: Layout::Fullscreen, height: Height::Full, ..Options::default() };
+        assert_eq!(parse(&["--fullscreen"]), Ok(Command::Run(expected)));
+
+        let expected = Options { layout: Layout::Fullscreen, height: Height::Lines(10), ..Options::default() };
+        assert_eq!(parse(&["--height", "10", "--fullscreen"]), Ok(Command::Run(expected)));
+    }
+
+    #[test]
+    fn test_parses_filter() {
+        let expected = Options { filter: Some("sear".to_string()), ..Options::default() };
+        assert_eq!(parse(&["--filter", "sear"]), Ok(Command::Run(expected)));
+
+        assert_eq!(parse(&["-f"]), Err(OptionsError::MissingValue("-f".to_string())));
+    }
+
+    #[test]
+    fn test_parses_no_wrap() {
+        let expected = Options { wraparound: false, ..Options::default() };
+        assert_eq!(parse(&["--no-wrap"]), Ok(Command::Run(expected)));
+    }
+
+    #[test]
+    fn test_parses_no_extended() {
+        let expected = Options { extended: false, ..Options::default() };
+        assert_eq!(parse(&["--no-extended"]), Ok(Command::Run(expected)));
+    }
+
+    #[test]
+    fn test_parses_styles() {
+        let expected = Options { match_style: Style { underline: true, ..Style::default() },
+                                 selection_style: Style::default(),
+                                 ..Options::default() };
+        assert_eq!(parse(&["--match-style", "underline", "--selection-style=none"]),
+                   Ok(Command::Run(expected)));
+
+        assert_eq!(parse(&["--match-style", "sparkly"]),
+                   Err(OptionsError::InvalidValue("--match-style".to_string(), "sparkly".to_string())));
+    }
+
+    #[test]
+    fn test_rejects_bad_height() {
+        assert_eq!(parse(&["--height", "abc"]),
+                   Err(OptionsError::InvalidValue("--height".to_string(), "abc".to_string())));
+        assert_eq!(parse(&["--height", "1"]),
+                   Err(OptionsError::InvalidValue("--height".to_string(), "1".to_string())));
+    }
+
+    #[test]
+    fn test_reports_missing_values_and_unknown_options() {
+        assert_eq!(parse(&["--search"]), Err(OptionsError::MissingValue("--search".to_string())));
+        assert_eq!(parse(&["--nope"]), Err(OptionsError::UnknownOption("--nope".to_string())));
+    }
+
+    #[test]
+    fn test_rejects_values_for_flags() {
+        for flag in ["--help", "--version", "--no-wrap", "--multi", "--no-extended", "--fullscreen"] {
+            assert_eq!(parse(&[&format!("{}=no", flag)]),
+                       Err(OptionsError::UnexpectedValue(flag.to_string())));
+        }
+        assert_eq!(OptionsError::UnexpectedValue("--multi".to_string()).to_string(),
+                   "--multi takes no value");
+    }
+
+    #[test]
+    fn test_help_and_version() {
+        assert_eq!(parse(&["--help"]), Ok(Command::Help));
+        assert_eq!(parse(&["-v"]), Ok(Command::Version));
+    }
+}
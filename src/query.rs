@@ -0,0 +1,398 @@
+use crate::score::{fold_case, Scorer};
+
+/// A parsed query, in fzf's extended syntax.
+///
+/// Terms are separated by spaces and must all match, in any order. Terms
+/// joined by ` | ` form a group that matches if any one of them does.
+///
+/// ```text
+/// sear         fuzzy match
+/// 'sear        exact substring
+/// ^spec        prefix
+/// .rb$         suffix
+/// !spec        doesn't contain spec; also !^spec and !.rb$
+/// rb$ | md$    either suffix
+/// ```
+///
+/// A backslash keeps a space in a term, `foo\ bar`, and makes an
+/// operator char a plain one: `\!`, `\'`, `\^`, `\$` and `\|`.
+///
+/// With extended syntax turned off the whole query is one fuzzy term,
+/// spaces and all.
+#[derive(Debug, PartialEq)]
+pub struct Query {
+    groups: Vec<Vec<Term>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Term {
+    pub kind: TermKind,
+    pub text: String,
+    /// Matches the choices the term without the `!` doesn't.
+    pub negated: bool,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TermKind {
+    Fuzzy,
+    Exact,
+    Prefix,
+    Suffix,
+    /// Both `^` and `$`: the whole choice.
+    Equal,
+}
+
+impl Query {
+    /// Parse the query in the extended syntax if `extended` is on, or
+    /// take it as it is if not.
+    pub fn new(query: &str, extended: bool) -> Query {
+        if extended { Query::parse(query) } else { Query::plain(query) }
+    }
+
+    pub fn parse(query: &str) -> Query {
+        let mut groups: Vec<Vec<Term>> = Vec::new();
+        let mut or_next = false;
+
+        for token in split_terms(query) {
+            if token == [('|', false)] {
+                // only joins terms that are both there
+                or_next = !groups.is_empty();
+                continue;
+            }
+
+            let term = match Term::parse(&token) {
+                Some(term) => term,
+                None => continue,
+            };
+
+            match groups.last_mut() {
+                Some(group) if or_next => group.push(term),
+                _ => groups.push(vec!(term)),
+            }
+            or_next = false;
+        }
+
+        Query { groups }
+    }
+
+    /// The query as a single fuzzy term, with no operators.
+    pub fn plain(query: &str) -> Query {
+        if query.is_empty() {
+            return Query { groups: Vec::new() };
+        }
+
+        let term = Term { kind: TermKind::Fuzzy, text: query.to_string(), negated: false };
+        Query { groups: vec!(vec!(term)) }
+    }
+
+    /// Groups of terms: a choice must match at least one term of each.
+    pub fn groups(&self) -> &[Vec<Term>] {
+        &self.groups
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// The score of the choice, or 0 if it doesn't match.
+    pub fn score(&self, choice: &str, scorer: &dyn Scorer) -> f64 {
+        self.score_with_positions(choice, scorer).map(|(score, _positions)| score).unwrap_or(0.0)
+    }
+
+    /// Score the choice and return the char indexes of the matched chars,
+    /// or `None` if it doesn't match.
+    ///
+    /// Terms are scored with `scorer`, and the score adds up the best
+    /// score of each group. Negated terms match
+    /// no chars and score nothing: a query of nothing else scores 1, like
+    /// an empty one, so the choices keep their input order.
+    pub fn score_with_positions(&self, choice: &str, scorer: &dyn Scorer) -> Option<(f64, Vec<usize>)> {
+        let mut total = 0.0;
+        let mut positions = Vec::new();
+
+        for group in &self.groups {
+            let (score, group_positions) = group.iter()
+                .filter_map(|term| term.score_with_positions(choice, scorer))
+                .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap())?;
+            total += score;
+            positions.extend(group_positions);
+        }
+
+        positions.sort_unstable();
+        positions.dedup();
+
+        if total > 0.0 {
+            Some((total, positions))
+        } else if self.has_positive_terms() {
+            // only matched through a negation where a match was possible:
+            // rank it after the choices that matched
+            Some((f64::MIN_POSITIVE, positions))
+        } else {
+            Some((1.0, positions))
+        }
+    }
+
+    fn has_positive_terms(&self) -> bool {
+        self.groups.iter().flatten().any(|term| !term.negated)
+    }
+}
+
+impl Term {
+    /// Parse one space-separated term, or `None` if there's nothing left
+    /// to match once its operators are taken off, e.g. a lone `!`.
+    fn parse(token: &[(char, bool)]) -> Option<Term> {
+        let (negated, token) = strip_operator(token, '!');
+        let (exact, token) = strip_operator(token, '\'');
+        let (prefix, token) = strip_operator(token, '^');
+
+        let (suffix, token) = match token.split_last() {
+            Some((&('$', false), rest)) if !rest.is_empty() => (true, rest),
+            _ => (false, token),
+        };
+
+        if token.is_empty() {
+            return None;
+        }
+        let text: String = token.iter().map(|&(c, _escaped)| c).collect();
+
+        let kind = match (prefix, suffix) {
+            (true, true) => TermKind::Equal,
+            (true, false) => TermKind::Prefix,
+            (false, true) => TermKind::Suffix,
+            // a fuzzy negation would rule out far too much
+            (false, false) if exact || negated => TermKind::Exact,
+            (false, false) => TermKind::Fuzzy,
+        };
+
+        Some(Term { kind, text, negated })
+    }
+
+    /// The score and matched positions if the term matches, the negation
+    /// taken into account.
+    fn score_with_positions(&self, choice: &str, scorer: &dyn Scorer) -> Option<(f64, Vec<usize>)> {
+        let matched = match self.kind {
+            TermKind::Fuzzy => scorer.score_with_positions(choice, &self.text),
+            _ => self.anchored_match(choice, scorer),
+        };
+
+        match (matched, self.negated) {
+            (Some(matched), false) => Some(matched),
+            (None, true) => Some((0.0, Vec::new())),
+            _ => None,
+        }
+    }
+
+    /// Match the text literally, where the kind of term allows, ignoring
+    /// case. It's scored as the scorer scores the text, so that terms of
+    /// every kind add up on the same scale.
+    fn anchored_match(&self, choice: &str, scorer: &dyn Scorer) -> Option<(f64, Vec<usize>)> {
+        let (lower_choice, char_indexes) = fold_case(choice);
+        let (lower_text, _) = fold_case(&self.text);
+        let len = lower_text.len();
+
+        let start = match self.kind {
+            TermKind::Prefix => Some(0).filter(|_| lower_choice.starts_with(&lower_text)),
+            TermKind::Suffix => lower_choice.len().checked_sub(len)
+                .filter(|_| lower_choice.ends_with(&lower_text)),
+            TermKind::Equal => Some(0).filter(|_| lower_choice == lower_text),
+            _ => lower_choice.windows(len).position(|window| window == &lower_text[..]),
+        }?;
+
+        let mut positions: Vec<usize> = char_indexes[start..start + len].to_vec();
+        positions.dedup();
+
+        Some((scorer.score(choice, &self.text), positions))
+    }
+}
+
+/// Take the operator off the front of the token, unless it's escaped.
+fn strip_operator(token: &[(char, bool)], operator: char) -> (bool, &[(char, bool)]) {
+    match token.split_first() {
+        Some((&(c, false), rest)) if c == operator => (true, rest),
+        _ => (false, token),
+    }
+}
+
+/// The chars a backslash escapes. Before anything else it's a plain
+/// backslash.
+const ESCAPABLE: [char; 6] = [' ', '!', '\'', '^', '$', '|'];
+
+/// Split the query on spaces, except those escaped with a backslash. Each
+/// char of a term comes with whether it was escaped.
+fn split_terms(query: &str) -> Vec<Vec<(char, bool)>> {
+    let mut terms = Vec::new();
+    let mut term = Vec::new();
+    let mut chars = query.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next_if(|next| ESCAPABLE.contains(next)) {
+                Some(escaped) => term.push((escaped, true)),
+                None => term.push((c, false)),
+            },
+            ' ' => {
+                if !term.is_empty() {
+                    terms.push(std::mem::take(&mut term));
+                }
+            },
+            _ => term.push((c, false)),
+        }
+    }
+
+    if !term.is_empty() {
+        terms.push(term);
+    }
+
+    terms
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::score::{Selecta, SmithWaterman};
+
+    fn term(kind: TermKind, text: &str, negated: bool) -> Term {
+        Term { kind, text: text.to_string(), negated }
+    }
+
+    fn matches(choice: &str, query: &str) -> bool {
+        Query::parse(query).score(choice, &Selecta) > 0.0
+    }
+
+    #[test]
+    fn test_parses_terms() {
+        let query = Query::parse("sear 'rb ^spec .rb$ ^README.md$");
+        assert!(query.groups() == [vec!(term(TermKind::Fuzzy, "sear", false)),
+                                   vec!(term(TermKind::Exact, "rb", false)),
+                                   vec!(term(TermKind::Prefix, "spec", false)),
+                                   vec!(term(TermKind::Suffix, ".rb", false)),
+                                   vec!(term(TermKind::Equal, "README.md", false))]);
+    }
+
+    #[test]
+    fn test_parses_negations_as_exact() {
+        let query = Query::parse("!spec !^lib !.md$");
+        assert!(query.groups() == [vec!(term(TermKind::Exact, "spec", true)),
+                                   vec!(term(TermKind::Prefix, "lib", true)),
+                                   vec!(term(TermKind::Suffix, ".md", true))]);
+    }
+
+    #[test]
+    fn test_parses_or_groups() {
+        let query = Query::parse("src rb$ | md$ | txt$");
+        assert!(query.groups() == [vec!(term(TermKind::Fuzzy, "src", false)),
+                                   vec!(term(TermKind::Suffix, "rb", false),
+                                        term(TermKind::Suffix, "md", false),
+                                        term(TermKind::Suffix, "txt", false))]);
+    }
+
+    #[test]
+    fn test_ignores_empty_terms_and_dangling_ors() {
+        assert!(Query::parse("").is_empty());
+        assert!(Query::parse("  !  '  ^  | ").is_empty());
+
+        let query = Query::parse("| a |");
+        assert!(query.groups() == [vec!(term(TermKind::Fuzzy, "a", false))]);
+
+        // a lone `$` is just a char to look for
+        let query = Query::parse("$");
+        assert!(query.groups() == [vec!(term(TermKind::Fuzzy, "$", false))]);
+    }
+
+    #[test]
+    fn test_parses_escaped_spaces() {
+        let query = Query::parse("a\\ long\\ string b");
+        assert!(query.groups() == [vec!(term(TermKind::Fuzzy, "a long string", false)),
+                                   vec!(term(TermKind::Fuzzy, "b", false))]);
+    }
+
+    #[test]
+    fn test_parses_escaped_operators() {
+        let query = Query::parse("\\!a \\'b \\^c d\\$ \\| !\\!e ^\\^f$");
+        assert!(query.groups() == [vec!(term(TermKind::Fuzzy, "!a", false)),
+                                   vec!(term(TermKind::Fuzzy, "'b", false)),
+                                   vec!(term(TermKind::Fuzzy, "^c", false)),
+                                   vec!(term(TermKind::Fuzzy, "d$", false)),
+                                   vec!(term(TermKind::Fuzzy, "|", false)),
+                                   vec!(term(TermKind::Exact, "!e", true)),
+                                   vec!(term(TermKind::Equal, "^f", false))]);
+    }
+
+    #[test]
+    fn test_keeps_other_backslashes() {
+        let query = Query::parse("a\\b c\\");
+        assert!(query.groups() == [vec!(term(TermKind::Fuzzy, "a\\b", false)),
+                                   vec!(term(TermKind::Fuzzy, "c\\", false))]);
+    }
+
+    #[test]
+    fn test_parses_plain_queries_as_one_fuzzy_term() {
+        let query = Query::new("!a 'b | c$", false);
+        assert!(query.groups() == [vec!(term(TermKind::Fuzzy, "!a 'b | c$", false))]);
+        assert!(Query::new("", false).is_empty());
+        assert!(Query::new("!a", true) == Query::parse("!a"));
+    }
+
+    #[test]
+    fn test_matches_every_term_in_any_order() {
+        assert!(matches("app/models/user.rb", "user models"));
+        assert!(matches("app/models/user.rb", "models user"));
+        assert!(!matches("app/models/user.rb", "user views"));
+    }
+
+    #[test]
+    fn test_matches_exact_and_anchored_terms() {
+        assert!(matches("spec/search_spec.rb", "'search"));
+        assert!(!matches("spec/search_spec.rb", "'serch"));
+        assert!(matches("spec/search_spec.rb", "^SPEC"));
+        assert!(!matches("spec/search_spec.rb", "^search"));
+        assert!(matches("spec/search_spec.rb", "spec.rb$"));
+        assert!(!matches("spec/search_spec.rb", "spec$"));
+        assert!(matches("search.rb", "^search.rb$"));
+        assert!(!matches("spec/search.rb", "^search.rb$"));
+    }
+
+    #[test]
+    fn test_matches_negations() {
+        assert!(matches("search.rb", "!spec"));
+        assert!(!matches("spec/search_spec.rb", "!spec"));
+        assert!(!matches("search.rb", "!.rb$"));
+        assert!(matches("search.rb", "sear !^spec"));
+        assert!(!matches("spec/search_spec.rb", "sear !^spec"));
+    }
+
+    #[test]
+    fn test_matches_any_term_of_a_group() {
+        assert!(matches("README.md", "rb$ | md$"));
+        assert!(matches("search.rb", "rb$ | md$"));
+        assert!(!matches("Cargo.toml", "rb$ | md$"));
+        assert!(matches("search.rb", "sear rb$ | md$"));
+    }
+
+    #[test]
+    fn test_scores_a_single_term_like_a_plain_query() {
+        for &(choice, query) in &[("search.rb", "sear"), ("spec/search_spec.rb", "sear"), ("a", "A")] {
+            assert!(Query::parse(query).score(choice, &Selecta) == Selecta.score(choice, query));
+            assert!(Query::parse(query).score(choice, &SmithWaterman) == SmithWaterman.score(choice, query));
+        }
+    }
+
+    #[test]
+    fn test_keeps_input_order_for_negations_alone() {
+        assert!(Query::parse("!spec").score("search.rb", &Selecta) == 1.0);
+        assert!(Query::parse("").score("search.rb", &Selecta) == 1.0);
+
+        let query = Query::parse("rb$ | !spec");
+        assert!(query.score("README.md", &Selecta) < query.score("search.rb", &Selecta));
+    }
+
+    #[test]
+    fn test_returns_positions_of_every_term() {
+        let (_score, positions) = Query::parse("'sp .rb$ !md").score_with_positions("spec/search.rb", &Selecta).unwrap();
+        assert!(positions == vec!(0, 1, 11, 12, 13));
+
+        let (_score, positions) = Query::parse("İ").score_with_positions("aİb", &Selecta).unwrap();
+        assert!(positions == vec!(1));
+    }
+}
@@ -0,0 +1,343 @@
+use super::{fold_case, original_positions, Scorer};
+
+// Extra credit for a matched char in a good spot, counted as part of a
+// matched char. A char only gets the biggest bonus that applies to it.
+const BOUNDARY_BONUS: f64 = 0.5;
+const CAMEL_CASE_BONUS: f64 = 0.5;
+const BASENAME_BONUS: f64 = 1.0;
+
+/// Selecta's algorithm: a short stretch of the choice containing the query
+/// chars in order wins. The score is the query length over the length of
+/// that stretch, normalized by the length of the choice.
+///
+/// Matched chars at the start of a word count for more: after one of
+/// `/ _ - .` or a space, at a camelCase hump, and most of all at the
+/// start of the basename.
+pub struct Selecta;
+
+impl Scorer for Selecta {
+    fn score_with_positions(&self, choice: &str, query: &str) -> Option<(f64, Vec<usize>)> {
+        if query.is_empty() {
+            return Some((1.0, Vec::new()));
+        }
+
+        if choice.is_empty() {
+            return None;
+        }
+
+        let (lower_choice, char_indexes) = fold_case(choice);
+        let (lower_query, _) = fold_case(query);
+        let choice_len = choice.chars().count() as f64;
+
+        // only the first of the chars a char folds to gets its bonus
+        let original_bonuses = bonuses(choice);
+        let bonuses: Vec<f64> = char_indexes.iter().enumerate().map(|(i, &original)| {
+            if i > 0 && char_indexes[i - 1] == original { 0.0 } else { original_bonuses[original] }
+        }).collect();
+
+        compute_best_match(&lower_choice, &lower_query, &bonuses).map(|(score, folded_positions)| {
+            (score / choice_len, original_positions(&folded_positions, &char_indexes))
+        })
+    }
+}
+
+/// The bonus for matching each char of the choice.
+fn bonuses(choice: &str) -> Vec<f64> {
+    let chars: Vec<char> = choice.chars().collect();
+    let basename_start = basename_start(&chars);
+
+    chars.iter().enumerate().map(|(i, &c)| {
+        if i == basename_start {
+            BASENAME_BONUS
+        } else if i == 0 || is_word_separator(chars[i - 1]) {
+            BOUNDARY_BONUS
+        } else if chars[i - 1].is_lowercase() && c.is_uppercase() {
+            CAMEL_CASE_BONUS
+        } else {
+            0.0
+        }
+    }).collect()
+}
+
+fn is_word_separator(c: char) -> bool {
+    matches!(c, '/' | '_' | '-' | '.' | ' ')
+}
+
+/// Where the last component of a path starts, ignoring trailing slashes.
+fn basename_start(chars: &[char]) -> usize {
+    let end = chars.iter().rposition(|&c| c != '/').map_or(0, |i| i + 1);
+    chars[..end].iter().rposition(|&c| c == '/').map_or(0, |i| i + 1)
+}
+
+/// Find the best scoring positions of the given characters: the matched
+/// chars plus their bonuses, over the length of the stretch they span.
+/// Each start is extended to its shortest match, and the leftmost of the
+/// best wins a tie.
+fn compute_best_match(haystack: &[char], needles: &[char], bonuses: &[f64]) -> Option<(f64, Vec<usize>)> {
+    let first_char = needles[0];
+    let rest = &needles[1..];
+
+    let first_indexes = find_char_in_string(haystack, first_char);
+
+    first_indexes.iter().filter_map(|&first_index|
+        find_match_positions(haystack, rest, first_index)
+    ).map(|positions| {
+        let match_length = positions[positions.len() - 1] - positions[0] + 1;
+        let bonus: f64 = positions.iter().map(|&i| bonuses[i]).sum();
+        ((needles.len() as f64 + bonus) / match_length as f64, positions)
+    }).fold(None, |best, (score, positions)| match best {
+        Some((best_score, _)) if best_score >= score => best,
+        _ => Some((score, positions)),
+    })
+}
+
+/// Find all occurrences of the character in the string, returning their indexes.
+fn find_char_in_string(haystack: &[char], needle: char) -> Vec<usize> {
+    haystack.iter().enumerate().filter(|&(_i, &c)|
+        c == needle
+    ).map(|(i, _c)|
+        i
+    ).collect()
+}
+
+/// Find each of the characters in the string, moving strictly left to right,
+/// and return where each one matched.
+fn find_match_positions(haystack: &[char], needles: &[char], first_index: usize) -> Option<Vec<usize>> {
+    let mut positions = vec!(first_index);
+    let mut last_index = first_index;
+    for needle in needles.iter() {
+        last_index = match find_from_offset(haystack, *needle, last_index + 1) {
+            Some(i) => i,
+            None => { return None; },
+        };
+        positions.push(last_index);
+    }
+
+    Some(positions)
+}
+
+/// Implements Ruby's `#index` method
+fn find_from_offset(haystack: &[char], needle: char, offset: usize) -> Option<usize> {
+    let h = &haystack[offset..];
+
+    h.iter().position(|&c| c == needle).map(|i| i + offset)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn score(choice: &str, query: &str) -> f64 {
+        Selecta.score(choice, query)
+    }
+
+    fn score_with_positions(choice: &str, query: &str) -> Option<(f64, Vec<usize>)> {
+        Selecta.score_with_positions(choice, query)
+    }
+
+    #[test]
+    fn test_scores_zero_when_choice_is_empty() {
+        assert!(score("", "a") == 0.0);
+    
+    }
+    
+    #[test]
+    fn test_scores_one_when_query_is_empty() {
+        assert!(score("a", "") == 1.0);
+    }
+    
+    #[test]
+    fn test_scores_zero_when_the_query_longer_than_choice() {
+        assert!(score("short", "longer") == 0.0);
+    }
+    
+    #[test]
+    fn test_scores_zero_when_query_does_not_match_at_all() {
+        assert!(score("a", "b") == 0.0);
+    }
+    
+    #[test]
+    fn test_scores_zero_when_only_prefix_of_query_matches() {
+        assert!(score("ab", "ac") == 0.0);
+    }
+    
+    #[test]
+    fn test_scores_greater_than_zero_when_matches() {
+        let given_choices: Vec<&str> = vec!("a", "ab", "ba", "bab");
+    
+        for choice in given_choices.iter() {
+            assert!(score(choice, "a") > 0.0);
+        }
+    
+        assert!(score("babababab", "aaaa") > 0.0);
+    }
+    
+    #[test]
+    fn test_scores_1_plus_bonuses_normalized_to_length_when_the_query_equals_choice() {
+        // the first char starts the basename
+        assert!(score("a", "a") == 2.0);
+        assert!(score("ab", "ab") == 0.75);
+        assert!(score("a long string", "a long string") ==
+                (13.0 + BASENAME_BONUS + 2.0 * BOUNDARY_BONUS) / 13.0 / 13.0);
+        assert!(score("spec/search_spec.rb", "sear") ==
+                (4.0 + BASENAME_BONUS) / 4.0 / "spec/search_spec.rb".len() as f64);
+    }
+    
+    #[test]
+    fn test_matches_punctuation() {
+        assert!(score("/! symbols $^", "/!$^") > 0.0);
+    }
+    
+    #[test]
+    fn test_is_case_insensitive() {
+        assert!(score("a", "A") == score("a", "a"));
+        assert!(score("A", "a") == score("a", "a"));
+    }
+    
+    #[test]
+    fn test_does_not_match_when_same_letter_is_repeated_in_choice() {
+        assert!(score("a", "aa") == 0.0);
+    }
+    
+    #[test]
+    fn test_scores_higher_for_better_matches() {
+        assert!(score("selecta.gemspec", "asp") > score("algorithm4_spec.rb", "asp"));
+        assert!(score("README.md", "em") > score("benchmark.rb", "em"));
+        assert!(score("search.rb", "sear") > score("spec/search_spec.rb", "sear"));
+    }
+    
+    #[test]
+    fn test_scores_shorter_matches_higher() {
+        assert!(score("fbb", "fbb") > score("foo bar baz", "fbb"));
+        assert!(score("foo", "foo") > score("longer foo", "foo"));
+        assert!(score("foo", "foo") > score("foo longer", "foo"));
+        assert!(score("1/2/3/4", "1/2/3") > score("1/9/2/3/4", "1/2/3"));
+    }
+    
+    #[test]
+    fn test_sometimes_score_longer_strings_higher_if_better_match() {
+        assert!(score("long 12 long", "12") > score("1 long 2", "12"));
+    }
+    
+    #[test]
+    fn test_scores_higher_of_two_matches_regardless_of_order() {
+        let tight = "12";
+        let loose = "1padding2";
+        let expect1 = tight.to_string() + loose;
+        let expect2 = loose.to_string() + tight;
+    
+        // the tight match gets the bonus for starting the basename in the first
+        assert!(score(&expect1, "12") == (2.0 + BASENAME_BONUS) / 2.0 / expect1.len() as f64);
+        assert!(score(&expect2, "12") == 1.0 / expect2.len() as f64);
+    }
+
+    #[test]
+    fn test_does_not_panic_on_multibyte_choices() {
+        assert!(score("é", "é") == 2.0);
+        assert!(score("aé", "é") > 0.0);
+        assert!(score("éa", "a") > 0.0);
+        assert!(score("日本語", "x") == 0.0);
+    }
+
+    #[test]
+    fn test_matches_accented_choices() {
+        assert!(score("café.txt", "fé") > 0.0);
+        assert!(score("naïve", "ïv") > 0.0);
+        assert!(score("résumé.pdf", "rsm") > score("résumé.pdf", "rpf"));
+    }
+
+    #[test]
+    fn test_normalizes_by_character_length() {
+        assert!(score("café", "café") == (4.0 + BASENAME_BONUS) / 4.0 / 4.0);
+        assert!(score("日本語", "日本語") == (3.0 + BASENAME_BONUS) / 3.0 / 3.0);
+    }
+
+    #[test]
+    fn test_is_case_insensitive_for_non_ascii() {
+        assert!(score("Über", "über") == score("über", "über"));
+        assert!(score("école", "ÉCOLE") == score("école", "école"));
+        assert!(score("ΣΟΦΙΑ", "σοφ") > 0.0);
+    }
+
+    #[test]
+    fn test_matches_cjk_choices() {
+        assert!(score("日本語のファイル.txt", "本フ") > 0.0);
+        assert!(score("日本語のファイル.txt", "フ本") == 0.0);
+        assert!(score("文件/报告.doc", "文报") > score("文件/其他/报告.doc", "文报"));
+    }
+
+    #[test]
+    fn test_positions_are_none_when_nothing_matches() {
+        assert!(score_with_positions("", "a").is_none());
+        assert!(score_with_positions("ab", "ac").is_none());
+    }
+
+    #[test]
+    fn test_positions_are_empty_for_empty_query() {
+        assert!(score_with_positions("abc", "") == Some((1.0, vec!())));
+    }
+
+    #[test]
+    fn test_positions_agree_with_score() {
+        for &(choice, query) in [("search.rb", "sear"), ("spec/search_spec.rb", "sear"),
+                                  ("foo bar baz", "fbb"), ("日本語のファイル", "本フ")].iter() {
+            let (s, _) = score_with_positions(choice, query).unwrap();
+            assert!(s == score(choice, query));
+        }
+    }
+
+    #[test]
+    fn test_positions_of_the_shortest_match() {
+        assert!(score_with_positions("spec/search_spec.rb", "sear").unwrap().1 == vec!(5, 6, 7, 8));
+        assert!(score_with_positions("1padding212", "12").unwrap().1 == vec!(9, 10));
+        assert!(score_with_positions("foo bar baz", "fbb").unwrap().1 == vec!(0, 4, 8));
+    }
+
+    #[test]
+    fn test_positions_prefer_leftmost_of_equal_matches() {
+        assert!(score_with_positions("ab ab", "ab").unwrap().1 == vec!(0, 1));
+    }
+
+    #[test]
+    fn test_positions_are_char_indexes() {
+        assert!(score_with_positions("résumé.pdf", "sp").unwrap().1 == vec!(2, 7));
+        assert!(score_with_positions("日本語のファイル", "本フ").unwrap().1 == vec!(1, 4));
+        assert!(score_with_positions("İx", "x").unwrap().1 == vec!(1));
+    }
+
+    #[test]
+    fn test_scores_matches_after_separators_higher() {
+        for separator in ['/', '_', '-', '.', ' '] {
+            let after = format!("xa{}b", separator);
+            let before = format!("xab{}", separator);
+            assert!(score(&after, "b") > score(&before, "b"));
+        }
+    }
+
+    #[test]
+    fn test_scores_camel_case_humps_higher() {
+        assert!(score("fooBar", "b") > score("foobar", "b"));
+        assert!(score("xFooBar", "fb") > score("xfoobar", "fb"));
+    }
+
+    #[test]
+    fn test_scores_the_start_of_the_basename_highest() {
+        assert!(score("ab_cd/ef", "e") > score("ab/cd_ef", "e"));
+        assert!(score("lib/search.rb", "s") > score("lib/xsearch.r", "s"));
+        assert!(score("search.rb", "sear") == (4.0 + BASENAME_BONUS) / 4.0 / 9.0);
+    }
+
+    #[test]
+    fn test_finds_the_basename_before_trailing_slashes() {
+        assert!(basename_start(&['a', '/', 'b', 'c', '/']) == 2);
+        assert!(basename_start(&['a', 'b']) == 0);
+        assert!(basename_start(&['/']) == 0);
+    }
+
+    #[test]
+    fn test_positions_prefer_bonuses_over_leftmost() {
+        assert!(score_with_positions("ab_cd/ab", "ab").unwrap().1 == vec!(6, 7));
+        assert!(score_with_positions("xab_ab", "ab").unwrap().1 == vec!(4, 5));
+    }
+}
@@ -0,0 +1,235 @@
+use std::cmp::max;
+
+use super::{fold_case, original_positions, Scorer};
+
+// The scores and bonuses of fzf's v2 algorithm.
+const SCORE_MATCH: i32 = 16;
+const SCORE_GAP_START: i32 = -3;
+const SCORE_GAP_EXTENSION: i32 = -1;
+const BONUS_BOUNDARY: i32 = SCORE_MATCH / 2;
+const BONUS_NON_WORD: i32 = SCORE_MATCH / 2;
+const BONUS_CAMEL_123: i32 = BONUS_BOUNDARY + SCORE_GAP_EXTENSION;
+const BONUS_CONSECUTIVE: i32 = -(SCORE_GAP_START + SCORE_GAP_EXTENSION);
+const BONUS_FIRST_CHAR_MULTIPLIER: i32 = 2;
+
+/// An fzf v2 style scorer: a Smith-Waterman alignment of the query chars
+/// against the choice, finding the best placement rather than the
+/// shortest. Each matched char scores, with bonuses for matching at word
+/// boundaries, camelCase humps and right after the previous match, and
+/// gaps between matches cost a little for each char skipped.
+pub struct SmithWaterman;
+
+impl Scorer for SmithWaterman {
+    fn score_with_positions(&self, choice: &str, query: &str) -> Option<(f64, Vec<usize>)> {
+        if query.is_empty() {
+            return Some((1.0, Vec::new()));
+        }
+
+        let (text, char_indexes) = fold_case(choice);
+        let (pattern, _) = fold_case(query);
+
+        // skip the alignment for the many choices that can't match at all
+        let mut rest = text.iter();
+        if !pattern.iter().all(|p| rest.any(|t| t == p)) {
+            return None;
+        }
+
+        let original_bonuses = bonuses(choice);
+        let bonuses: Vec<i32> = char_indexes.iter().map(|&i| original_bonuses[i]).collect();
+
+        let (score, folded_positions) = align(&text, &pattern, &bonuses)?;
+
+        // a match always scores above zero, however far apart its chars are
+        Some((max(score, 1) as f64, original_positions(&folded_positions, &char_indexes)))
+    }
+}
+
+/// One cell of the alignment: the best score for matching the query up
+/// to this char here.
+#[derive(Clone, Copy)]
+struct Cell {
+    score: i32,
+    /// Where the previous query char matched, to trace the alignment back.
+    from: usize,
+    /// The bonus of the first char in the run of consecutive matches this
+    /// one ends, which the rest of the run shares.
+    run_bonus: i32,
+}
+
+/// Find the best alignment of `pattern` in `text`, returning its score and
+/// where each pattern char matched.
+fn align(text: &[char], pattern: &[char], bonuses: &[i32]) -> Option<(i32, Vec<usize>)> {
+    let mut rows: Vec<Vec<Option<Cell>>> = Vec::with_capacity(pattern.len());
+
+    for (i, &p) in pattern.iter().enumerate() {
+        let mut row: Vec<Option<Cell>> = vec![None; text.len()];
+        // the best cell in the row above to reach this column with a gap
+        let mut gap: Option<(i32, usize)> = None;
+
+        for (j, &t) in text.iter().enumerate() {
+            if i > 0 && j >= 2 {
+                gap = gap.map(|(score, from)| (score + SCORE_GAP_EXTENSION, from));
+                if let Some(cell) = rows[i - 1][j - 2] {
+                    let opened = cell.score + SCORE_GAP_START;
+                    if gap.is_none_or(|(score, _)| opened > score) {
+                        gap = Some((opened, j - 2));
+                    }
+                }
+            }
+
+            if t != p {
+                continue;
+            }
+
+            if i == 0 {
+                row[j] = Some(Cell { score: SCORE_MATCH + bonuses[j] * BONUS_FIRST_CHAR_MULTIPLIER,
+                                     from: j,
+                                     run_bonus: bonuses[j] });
+                continue;
+            }
+
+            let consecutive = if j > 0 { rows[i - 1][j - 1] } else { None }.map(|cell| {
+                let run_bonus = max(cell.run_bonus, bonuses[j]);
+                let score = cell.score + SCORE_MATCH + max(run_bonus, BONUS_CONSECUTIVE);
+                Cell { score, from: j - 1, run_bonus }
+            });
+
+            let after_gap = gap.map(|(score, from)| {
+                Cell { score: score + SCORE_MATCH + bonuses[j], from, run_bonus: bonuses[j] }
+            });
+
+            row[j] = match (consecutive, after_gap) {
+                (Some(c), Some(g)) => Some(if g.score > c.score { g } else { c }),
+                (c, g) => c.or(g),
+            };
+        }
+
+        rows.push(row);
+    }
+
+    // the leftmost of the best scoring ends
+    let (end, best) = rows[pattern.len() - 1].iter().enumerate()
+        .filter_map(|(j, cell)| cell.map(|cell| (j, cell)))
+        .fold(None, |best: Option<(usize, Cell)>, (j, cell)| match best {
+            Some((_, b)) if b.score >= cell.score => best,
+            _ => Some((j, cell)),
+        })?;
+
+    let mut positions = vec![end; pattern.len()];
+    for i in (1..pattern.len()).rev() {
+        let cell = rows[i][positions[i]].expect("an aligned char");
+        positions[i - 1] = cell.from;
+    }
+
+    Some((best.score, positions))
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum CharClass {
+    NonWord,
+    Lower,
+    Upper,
+    Letter,
+    Number,
+}
+
+fn char_class(c: char) -> CharClass {
+    if c.is_lowercase() {
+        CharClass::Lower
+    } else if c.is_uppercase() {
+        CharClass::Upper
+    } else if c.is_numeric() {
+        CharClass::Number
+    } else if c.is_alphabetic() {
+        CharClass::Letter
+    } else {
+        CharClass::NonWord
+    }
+}
+
+/// The bonus for matching each char of the choice, from its class and
+/// the class of the char before it. The start counts as a boundary.
+fn bonuses(choice: &str) -> Vec<i32> {
+    let mut prev = CharClass::NonWord;
+
+    choice.chars().map(|c| {
+        let class = char_class(c);
+        let bonus = match (prev, class) {
+            (CharClass::NonWord, CharClass::NonWord) => BONUS_NON_WORD,
+            (CharClass::NonWord, _) => BONUS_BOUNDARY,
+            (CharClass::Lower, CharClass::Upper) => BONUS_CAMEL_123,
+            (CharClass::Number, CharClass::Number) => 0,
+            (_, CharClass::Number) => BONUS_CAMEL_123,
+            (_, CharClass::NonWord) => BONUS_NON_WORD,
+            _ => 0,
+        };
+        prev = class;
+        bonus
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn score(choice: &str, query: &str) -> f64 {
+        SmithWaterman.score(choice, query)
+    }
+
+    fn positions(choice: &str, query: &str) -> Vec<usize> {
+        SmithWaterman.score_with_positions(choice, query).unwrap().1
+    }
+
+    #[test]
+    fn test_matches_query_chars_in_order() {
+        assert!(score("search.rb", "srb") > 0.0);
+        assert!(score("search.rb", "SEAR") > 0.0);
+        assert!(score("search.rb", "rs") == 0.0);
+        assert!(score("ab", "abc") == 0.0);
+        assert!(score("", "a") == 0.0);
+        assert!(score("a", "aa") == 0.0);
+    }
+
+    #[test]
+    fn test_scores_matches_and_bonuses() {
+        // a boundary at the start, doubled for the first char, then a
+        // consecutive char
+        assert!(score("ab", "ab") == (SCORE_MATCH + BONUS_BOUNDARY * 2 + SCORE_MATCH + BONUS_BOUNDARY) as f64);
+        assert!(score("xab", "ab") == (SCORE_MATCH + SCORE_MATCH + BONUS_CONSECUTIVE) as f64);
+        assert!(score("xaxxb", "ab") == (SCORE_MATCH * 2 + SCORE_GAP_START + SCORE_GAP_EXTENSION) as f64);
+    }
+
+    #[test]
+    fn test_prefers_word_boundaries() {
+        assert!(score("foo-bar", "fb") > score("fooxbar", "fb"));
+        assert!(score("foo/bar", "b") > score("foobar", "b"));
+        assert!(score("fooBar", "fb") > score("foobar", "fb"));
+        assert!(score("foo2", "o2") > score("fooz", "oz"));
+    }
+
+    #[test]
+    fn test_prefers_consecutive_matches() {
+        assert!(score("xabx", "ab") > score("xaxbx", "ab"));
+        assert!(score("xxsearchxx", "sear") > score("sxexaxrxxx", "sear"));
+    }
+
+    #[test]
+    fn test_positions_of_the_best_alignment() {
+        assert!(positions("abc_b", "b") == vec!(4));
+        assert!(positions("spec/search_spec.rb", "sear") == vec!(5, 6, 7, 8));
+        assert!(positions("app/models/user.rb", "mu") == vec!(4, 11));
+    }
+
+    #[test]
+    fn test_positions_are_char_indexes() {
+        assert!(positions("résumé.pdf", "sp") == vec!(2, 7));
+        assert!(positions("İx", "x") == vec!(1));
+    }
+
+    #[test]
+    fn test_long_gaps_still_match() {
+        let choice = format!("a{}b", "x".repeat(100));
+        assert!(score(&choice, "ab") > 0.0);
+    }
+}
@@ -0,0 +1,63 @@
+use super::{fold_case, original_positions, Scorer};
+
+/// Matches the query only as it's typed, ignoring case: the leftmost
+/// occurrence of it in the choice. The score is the share of the choice
+/// the query covers, so shorter choices rank higher.
+pub struct Substring;
+
+impl Scorer for Substring {
+    fn score_with_positions(&self, choice: &str, query: &str) -> Option<(f64, Vec<usize>)> {
+        if query.is_empty() {
+            return Some((1.0, Vec::new()));
+        }
+
+        let (lower_choice, char_indexes) = fold_case(choice);
+        let (lower_query, _) = fold_case(query);
+
+        let start = lower_choice.windows(lower_query.len()).position(|window| window == &lower_query[..])?;
+        let folded_positions: Vec<usize> = (start..start + lower_query.len()).collect();
+
+        let score = query.chars().count() as f64 / choice.chars().count() as f64;
+        Some((score, original_positions(&folded_positions, &char_indexes)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn score(choice: &str, query: &str) -> f64 {
+        Substring.score(choice, query)
+    }
+
+    #[test]
+    fn test_matches_only_substrings() {
+        assert!(score("search.rb", "arch") > 0.0);
+        assert!(score("search.rb", "sear") > 0.0);
+        assert!(score("search.rb", "srch") == 0.0);
+        assert!(score("", "a") == 0.0);
+        assert!(score("ab", "abc") == 0.0);
+    }
+
+    #[test]
+    fn test_is_case_insensitive() {
+        assert!(score("README.md", "readme") > 0.0);
+        assert!(score("Über", "üB") > 0.0);
+    }
+
+    #[test]
+    fn test_scores_the_share_of_the_choice_matched() {
+        assert!(score("search", "search") == 1.0);
+        assert!(score("search.rb", "sear") == 4.0 / 9.0);
+        assert!(score("search.rb", "sear") > score("spec/search_spec.rb", "sear"));
+    }
+
+    #[test]
+    fn test_positions_of_the_leftmost_occurrence() {
+        assert!(Substring.score_with_positions("ab ab", "ab").unwrap().1 == vec!(0, 1));
+        assert!(Substring.score_with_positions("spec/search_spec.rb", "sear").unwrap().1 == vec!(5, 6, 7, 8));
+        assert!(Substring.score_with_positions("résumé.pdf", "umé").unwrap().1 == vec!(3, 4, 5));
+        assert!(Substring.score_with_positions("İx", "x").unwrap().1 == vec!(1));
+    }
+}
@@ -1,57 +1,378 @@
 
-use tty::TTY;
-use ansi::ANSI;
+use crate::tty::{SavedState, TTY};
+use crate::ansi::{ANSIColor, Style, ANSI};
+use crate::frame::{text_width, Cell, Frame};
+use crate::key::KeyEvent;
+use crate::search::Search;
+use crate::signals;
 
-use std::io::stdio::stdout_raw;
+use std::cmp::min;
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::ops::{Deref, DerefMut};
+use std::panic;
+use std::sync::{Mutex, Once};
+use std::time::Duration;
 
+pub const DEFAULT_MATCH_STYLE: Style =
+    Style { bold: true, underline: false, inverse: false, fg: Some(ANSIColor::Green) };
+pub const DEFAULT_SELECTION_STYLE: Style =
+    Style { bold: false, underline: false, inverse: true, fg: None };
+
+/// The terminal to restore, if a `ScreenGuard` is active. Shared with the
+/// panic hook, and taken by whichever of the two restores first.
+static SAVED_TERMINAL: Mutex<Option<SavedTerminal>> = Mutex::new(None);
+static PANIC_HOOK: Once = Once::new();
+
+struct SavedTerminal {
+    state: SavedState,
+    /// What to write to put away the picker as it's currently laid out.
+    cleanup: Vec<u8>,
+}
+
+/// Where on the terminal the picker is drawn.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Layout {
+    /// On lines reserved below the cursor, leaving the rest of the terminal
+    /// as it was. Those lines are erased on exit.
+    Inline,
+    /// At the top of the alternate screen, which is put away on exit.
+    Fullscreen,
+}
+
+/// The picker UI. Everything is drawn on /dev/tty, leaving stdout for the
+/// final selection so that `cd $(find . -type d | selecta)` works.
 pub struct Screen {
     tty: TTY,
-    ansi: ANSI,
-    original_tty_state: String,
+    ansi: ANSI<BufWriter<File>>,
+    match_style: Style,
+    selection_style: Style,
+    layout: Layout,
+    /// Whether choices are still being read, to say so on the prompt line.
+    reading: bool,
+    /// The screen line the picker starts on, and how many lines it has.
+    top: u16,
+    lines: u16,
+    /// What's on the terminal now, to draw the next frame against.
+    previous_frame: Option<Frame>,
 }
 
 impl Screen {
-    pub fn with_screen() -> Screen {
-        let tty = TTY::new().unwrap();
-        let ansi = ANSI::new(stdout_raw());
-        Screen::new(tty, ansi)
+    pub fn with_screen() -> io::Result<Screen> {
+        let tty = TTY::new()?;
+        let ansi = ANSI::new(BufWriter::new(tty.output()?));
+        Ok(Screen::new(tty, ansi))
+    }
+
+    pub fn new(tty: TTY, ansi: ANSI<BufWriter<File>>) -> Screen {
+        Screen { tty,
+                 ansi,
+                 match_style: DEFAULT_MATCH_STYLE,
+                 selection_style: DEFAULT_SELECTION_STYLE,
+                 layout: Layout::Inline,
+                 reading: false,
+                 top: 0,
+                 lines: 0,
+                 previous_frame: None }
+    }
+
+    /// Make room for a picker of `lines` lines, prompt included, to be
+    /// drawn in `layout`. Call this once the terminal is configured.
+    ///
+    /// Inline, the picker starts on the cursor's line, or the one below it
+    /// if there is text before the cursor, and the terminal is scrolled up
+    /// if there isn't room below. A terminal that can't tell us where the
+    /// cursor is gets the picker at the bottom of the screen.
+    pub fn start(&mut self, layout: Layout, lines: u16) -> io::Result<()> {
+        self.layout = layout;
+
+        match layout {
+            Layout::Inline => {
+                self.top = match self.tty.cursor_position() {
+                    Ok((line, column)) if column > 0 => line + 1,
+                    Ok((line, _column)) => line,
+                    // The terminal doesn't answer: start just past the bottom
+                    // of the screen, so everything above is scrolled up out of
+                    // the way rather than drawn over.
+                    Err(ref e) if e.kind() == io::ErrorKind::TimedOut => self.height()?,
+                    Err(e) => return Err(e),
+                };
+            },
+            Layout::Fullscreen => {
+                self.ansi.alternate_screen(true);
+                self.ansi.clear();
+                self.top = 0;
+            },
+        }
+
+        self.reserve(lines)
+    }
+
+    /// Change the number of lines the picker takes, e.g. after the terminal
+    /// was resized. Everything is drawn again on the next render.
+    pub fn resize(&mut self, lines: u16) -> io::Result<()> {
+        self.clear();
+        self.reserve(lines)
+    }
+
+    /// Take `lines` lines from `top` down, scrolling up what's on the
+    /// terminal if they don't fit.
+    fn reserve(&mut self, lines: u16) -> io::Result<()> {
+        let height = self.height()?;
+        let lines = min(lines, height);
+
+        if self.layout == Layout::Inline && lines > 0 {
+            // Newlines scroll the terminal at the bottom, where moving the
+            // cursor down wouldn't. `top` may be just past the last line.
+            let start = min(self.top, height - 1);
+            let newlines = (self.top - start + lines - 1) as usize;
+            self.ansi.setpos(start, 0);
+            self.ansi.write("\n".repeat(newlines));
+            self.top = min(self.top, height - lines);
+        }
+
+        self.lines = lines;
+        self.previous_frame = None;
+        self.ansi.flush();
+        set_cleanup(self.cleanup());
+        Ok(())
     }
 
-    pub fn new(tty: TTY, ansi: ANSI) -> Screen {
+    /// What to write to the terminal to put away the picker.
+    fn cleanup(&self) -> Vec<u8> {
+        let mut cleanup = ANSI::new(Vec::new());
+        cleanup.reset();
+        match self.layout {
+            Layout::Inline => {
+                for line in self.top..self.top + self.lines {
+                    cleanup.setpos(line, 0);
+                    cleanup.clear_line();
+                }
+                // leave the cursor where the picker was, for what comes next
+                if self.lines > 0 {
+                    cleanup.setpos(self.top, 0);
+                }
+            },
+            Layout::Fullscreen => cleanup.alternate_screen(false),
+        }
+        cleanup.show_cursor();
+        cleanup.into_inner()
+    }
 
-        // TODO this is bonkers, but ok
-        let mut s = Screen { tty: tty, ansi: ansi, original_tty_state: String::from_str("") };
-        let tty_state = s.tty.stty("-g");
-        s.original_tty_state = tty_state;
-        s
+    /// Set how matched characters and the selected line are drawn.
+    pub fn set_styles(&mut self, match_style: Style, selection_style: Style) {
+        self.match_style = match_style;
+        self.selection_style = selection_style;
     }
 
-    pub fn configure_tty(&mut self) {
-        // -echo: terminal doesn't echo typed characters back to the terminal
-        //# -icanon: terminal doesn't interpret special characters (like backspace)
-        self.tty.stty("-echo -icanon");
+    pub fn configure_tty(&mut self) -> io::Result<()> {
+        self.tty.configure()
     }
 
-    pub fn restore_tty(&mut self) {
-        let arg = format!("{}", self.original_tty_state);
-        self.tty.stty(arg.as_slice());
+    pub fn restore_tty(&mut self) -> io::Result<()> {
+        self.tty.restore()
     }
 
     pub fn move_cursor(&mut self, line: u16, column: u16) {
         self.ansi.setpos(line, column);
     }
 
-    pub fn height(&mut self) -> u16 {
-        let (_, height) = self.size();
-        height
+    pub fn get_key(&mut self) -> io::Result<KeyEvent> {
+        self.tty.get_key()
+    }
+
+    /// Wait for a key until the timeout, if there is one.
+    pub fn get_key_timeout(&mut self, timeout: Option<Duration>) -> io::Result<Option<KeyEvent>> {
+        self.tty.get_key_timeout(timeout)
+    }
+
+    pub fn set_reading(&mut self, reading: bool) {
+        self.reading = reading;
+    }
+
+    /// Draw the prompt line followed by the visible matches, with the
+    /// matched characters and the current selection styled.
+    ///
+    /// Only what changed since the last render is sent to the terminal,
+    /// in a single write.
+    pub fn render(&mut self, search: &Search) -> io::Result<()> {
+        let width = self.width()?;
+        let mut frame = Frame::new(width, self.lines);
+
+        let prompt = format!("> {}", search.get_query());
+        frame.set_text(0, &self.prompt_line(&prompt, search, width), Style::default());
+
+        let query = search.parsed_query();
+        let offset = search.get_scroll_offset();
+        for (i, choice) in search.visible_matches().iter().enumerate() {
+            let selected = offset + i as u64 == search.get_index();
+            let positions = query.score_with_positions(choice, search.scorer())
+                .map(|(_score, positions)| positions)
+                .unwrap_or_default();
+
+            let mut cells = Vec::new();
+            if search.is_multi() {
+                let marker = if search.is_marked(offset + i as u64) { '*' } else { ' ' };
+                cells.push(Cell::new(marker, self.line_style(selected)));
+                cells.push(Cell::new(' ', self.line_style(selected)));
+            }
+            cells.extend(self.choice_cells(choice, &positions, selected));
+            frame.set_line(i + 1, cells);
+        }
+
+        let mut out = ANSI::new(Vec::new());
+        out.hide_cursor();
+        frame.draw(self.top, self.previous_frame.as_ref(), &mut out);
+        let before_cursor: String = search.get_query().chars().take(search.get_cursor()).collect();
+        let column = ("> ".len() + text_width(&before_cursor)) as u16;
+        out.setpos(self.top, column);
+        out.show_cursor();
+
+        self.ansi.write_bytes(&out.into_inner());
+        self.ansi.flush();
+        self.previous_frame = Some(frame);
+        Ok(())
+    }
+
+    /// Erase the picker's lines.
+    pub fn clear(&mut self) {
+        match self.layout {
+            Layout::Inline => {
+                for line in self.top..self.top + self.lines {
+                    self.ansi.setpos(line, 0);
+                    self.ansi.clear_line();
+                }
+            },
+            Layout::Fullscreen => self.ansi.clear(),
+        }
+        self.ansi.flush();
+        self.previous_frame = None;
     }
 
-    pub fn width(&mut self) -> u16 {
-        let (width, _) = self.size();
-        width
+    /// The prompt with the number of matches out of the number of choices
+    /// on the right, if there's room for it.
+    fn prompt_line(&self, prompt: &str, search: &Search, width: u16) -> String {
+        let reading = if self.reading { "reading… " } else { "" };
+        let status = format!("{}{}/{}", reading, search.get_matches().len(), search.get_choice_count());
+
+        // keep clear of the last column, where some terminals wrap
+        let used = text_width(prompt) + text_width(&status) + 1;
+        match (width as usize).checked_sub(used) {
+            Some(space) if space > 1 => format!("{}{}{}", prompt, " ".repeat(space), status),
+            _ => prompt.to_string(),
+        }
     }
-    
-    fn size(&mut self) -> (u16, u16) {
-        self.tty.winsize().unwrap()
+
+    /// The cells for a choice with the chars at `positions` in the match
+    /// style, on top of the selection style if it's selected.
+    fn choice_cells(&self, choice: &str, positions: &[usize], selected: bool) -> Vec<Cell> {
+        let base = self.line_style(selected);
+        let highlighted = base.combine(self.match_style);
+        let mut positions = positions.iter().peekable();
+
+        choice.chars().enumerate().map(|(i, c)| {
+            if positions.peek() == Some(&&i) {
+                positions.next();
+                Cell::new(c, highlighted)
+            } else {
+                Cell::new(c, base)
+            }
+        }).collect()
     }
+
+    fn line_style(&self, selected: bool) -> Style {
+        if selected { self.selection_style } else { Style::default() }
+    }
+
+    pub fn height(&mut self) -> io::Result<u16> {
+        let (_, height) = self.size()?;
+        Ok(height)
+    }
+
+    pub fn width(&mut self) -> io::Result<u16> {
+        let (width, _) = self.size()?;
+        Ok(width)
+    }
+
+    fn size(&mut self) -> io::Result<(u16, u16)> {
+        self.tty.winsize()
+    }
+}
+
+/// Owns the `Screen` while the terminal is configured for the picker.
+///
+/// When dropped, or if we panic or are sent SIGINT, SIGTERM or SIGHUP, the
+/// picker is put away, the cursor is shown and the original terminal
+/// settings are restored.
+pub struct ScreenGuard {
+    screen: Screen,
+}
+
+impl ScreenGuard {
+    pub fn new(screen: Screen) -> io::Result<ScreenGuard> {
+        *lock_saved_terminal() = Some(SavedTerminal { state: screen.tty.saved_state(),
+                                                      cleanup: screen.cleanup() });
+
+        // Set up the guard before touching the terminal, so it is put back
+        // even if configuring fails halfway.
+        let mut guard = ScreenGuard { screen };
+
+        PANIC_HOOK.call_once(|| {
+            let default_hook = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                // restore first, so the message isn't hidden or cleared away
+                restore_terminal();
+                default_hook(info);
+            }));
+        });
+
+        signals::install_termination_handlers()?;
+        guard.screen.configure_tty()?;
+
+        Ok(guard)
+    }
+}
+
+impl Deref for ScreenGuard {
+    type Target = Screen;
+
+    fn deref(&self) -> &Screen {
+        &self.screen
+    }
+}
+
+impl DerefMut for ScreenGuard {
+    fn deref_mut(&mut self) -> &mut Screen {
+        &mut self.screen
+    }
+}
+
+impl Drop for ScreenGuard {
+    fn drop(&mut self) {
+        self.screen.ansi.flush();
+        restore_terminal();
+    }
+}
+
+/// Put away the picker, show the cursor and restore the terminal settings,
+/// unless that has already been done.
+fn restore_terminal() {
+    let saved = lock_saved_terminal().take();
+
+    if let Some(saved) = saved {
+        saved.state.restore(&saved.cleanup);
+    }
+}
+
+/// Keep the cleanup for the picker's current layout, if a `ScreenGuard`
+/// is active.
+fn set_cleanup(cleanup: Vec<u8>) {
+    if let Some(ref mut saved) = *lock_saved_terminal() {
+        saved.cleanup = cleanup;
+    }
+}
+
+fn lock_saved_terminal() -> std::sync::MutexGuard<'static, Option<SavedTerminal>> {
+    // a panic while holding the lock mustn't stop us restoring the terminal
+    SAVED_TERMINAL.lock().unwrap_or_else(|e| e.into_inner())
 }
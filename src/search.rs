@@ -1,14 +1,30 @@
-use config::Configuration;
-use score::score;
-use std::cmp::min;
+use crate::config::Configuration;
+use crate::query::Query;
+use crate::score::Scorer;
+use std::cmp::{max, min};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 pub struct Search {
     config: Configuration,
     choices: Vec<String>,
     index: u64,
+    /// Index of the first match shown in the visible window.
+    scroll_offset: u64,
     query: String,
+    /// Position of the editing cursor in the query, in chars.
+    cursor: usize,
+    /// The text most recently removed by a kill command, for `yank`.
+    kill_buffer: String,
     done: bool,
     matches: Vec<String>,
+    /// The score of each match, best first, for merging in new choices.
+    scores: Vec<f64>,
+    /// The index in `choices` of each match, which tells duplicate lines
+    /// apart.
+    ids: Vec<usize>,
+    /// The ids of the choices marked in multi mode, so they stay marked
+    /// whichever row they end up on as the query changes.
+    marked: HashSet<usize>,
 }
 
 impl Search {
@@ -19,24 +35,46 @@ impl Search {
            done: bool,
            matches: Option<Vec<String>>) -> Search {
 
-        let m = match matches {
-            Some(m) => m,
-            _ => compute_matches(&choices, query.as_slice())
+        let parsed = Query::new(&query, config.get_extended());
+        let scored = match matches {
+            Some(m) => {
+                let scorer = config.get_algorithm().scorer();
+                // Give each match the first choice with its text that no
+                // earlier match took. A match that isn't a choice is dropped.
+                let mut ids: HashMap<&str, VecDeque<usize>> = HashMap::new();
+                for (id, choice) in choices.iter().enumerate() {
+                    ids.entry(choice.as_str()).or_default().push_back(id);
+                }
+                m.into_iter().filter_map(|choice| {
+                    let id = ids.get_mut(choice.as_str())?.pop_front()?;
+                    Some((parsed.score(&choice, scorer), id, choice))
+                }).collect()
+            },
+            _ => compute_scored_matches(&choices, &parsed, config.get_algorithm().scorer())
         };
+        let (scores, ids, m) = unzip_matches(scored);
 
-        Search { config: config,
-                 choices: choices,
-                 index: index,
-                 query: query,
-                 done: done,
-                 matches: m }
+        let cursor = query.chars().count();
+        let search = Search { config,
+                              choices,
+                              index: 0,
+                              scroll_offset: 0,
+                              query,
+                              cursor,
+                              kill_buffer: String::new(),
+                              done,
+                              matches: m,
+                              scores,
+                              ids,
+                              marked: HashSet::new() };
+        search.move_to(index)
     }
 
     pub fn blank(config: Configuration) -> Search {
         let choices = config.get_choices();
         let initial_search = config.get_initial_search();
 
-        Search::new(config, 
+        Search::new(config,
                     choices,
                     0,
                     initial_search,
@@ -45,84 +83,380 @@ impl Search {
     }
 
     pub fn down(self) -> Search {
-        let max_visible_choices = self.max_visible_choices();
-
-        if max_visible_choices > 0 {
-            Search::new(self.config,
-                        self.choices,
-                        (self.index + 1) % max_visible_choices,
-                        self.query,
-                        self.done,
-                        None)
-        } else {
-            self
+        let count = self.matches.len() as u64;
+
+        if count == 0 {
+            return self;
         }
+
+        let index = if self.index + 1 < count {
+            self.index + 1
+        } else if self.config.get_wraparound() {
+            0
+        } else {
+            self.index
+        };
+
+        self.move_to(index)
     }
 
     pub fn up(self) -> Search {
-        let max_visible_choices = self.max_visible_choices();
-
-        if max_visible_choices > 0 {
-            let mut index;
+        let count = self.matches.len() as u64;
 
-            // Rust handles negative modulo differently than ruby
-            if self.index == 0 {
-                index = max_visible_choices - 1;
-            } else {
-                index = self.index - 1;
-            }
+        if count == 0 {
+            return self;
+        }
 
-            Search::new(self.config,
-                        self.choices,
-                        index,
-                        self.query,
-                        self.done,
-                        None)
+        let index = if self.index > 0 {
+            self.index - 1
+        } else if self.config.get_wraparound() {
+            count - 1
         } else {
-            self
+            self.index
+        };
+
+        self.move_to(index)
+    }
+
+    /// Move down by one window of visible choices, stopping at the last match.
+    pub fn page_down(self) -> Search {
+        let count = self.matches.len() as u64;
+
+        if count == 0 {
+            return self;
         }
+
+        let index = min(self.index + self.page_size(), count - 1);
+        self.move_to(index)
     }
 
-    fn max_visible_choices(&self) -> u64 {
-        min(self.config.get_visible_choices() as usize, self.matches.len()) as u64
+    /// Move up by one window of visible choices, stopping at the first match.
+    pub fn page_up(self) -> Search {
+        let index = self.index.saturating_sub(self.page_size());
+        self.move_to(index)
     }
 
-    pub fn append_search_string(self, string: &str) -> Search {
+    pub fn first(self) -> Search {
+        self.move_to(0)
+    }
 
-        let search = Search::new(self.config, 
-                    self.choices,
-                    0,
-                    self.query + string,
-                    self.done,
-                    None);
+    pub fn last(self) -> Search {
+        let index = (self.matches.len() as u64).saturating_sub(1);
+        self.move_to(index)
+    }
 
-        search
+    /// Adjust to a new screen height, keeping the selection in range and
+    /// inside the visible window.
+    pub fn resize(mut self, screen_height: u16) -> Search {
+        self.config.resize(screen_height);
+
+        let count = self.matches.len() as u64;
+        let visible = self.config.get_visible_choices() as u64;
+        let index = min(self.index, count.saturating_sub(1));
+
+        // don't leave blank rows at the bottom if the window grew
+        self.scroll_offset = min(self.scroll_offset, count.saturating_sub(visible));
+        self.move_to(index)
+    }
+
+    fn page_size(&self) -> u64 {
+        max(self.config.get_visible_choices() as u64, 1)
+    }
+
+    /// Add choices read after the search started, matching them against
+    /// the query. The same choice stays selected, on the same row of the
+    /// window if it can.
+    pub fn add_choices(mut self, choices: Vec<String>) -> Search {
+        if choices.is_empty() {
+            return self;
+        }
+
+        let first_id = self.choices.len();
+        let new_matches: Vec<_> = compute_scored_matches(&choices, &self.parsed_query(), self.scorer()).into_iter()
+            .map(|(score, id, choice)| (score, first_id + id, choice))
+            .collect();
+        self.choices.extend(choices);
+
+        if new_matches.is_empty() {
+            return self;
+        }
+
+        let selected = self.index as usize;
+        let row = self.index - self.scroll_offset;
+
+        let old_matches = self.scores.drain(..).zip(self.ids.drain(..)).zip(self.matches.drain(..))
+            .map(|((score, id), choice)| (score, id, choice));
+        let (merged, selected) = merge_matches(old_matches, new_matches, selected);
+        (self.scores, self.ids, self.matches) = unzip_matches(merged);
+
+        let index = selected as u64;
+        self.scroll_offset = index.saturating_sub(row);
+        self.move_to(index)
+    }
+
+    /// Select the match at `index` and scroll just enough to keep it inside
+    /// the visible window.
+    fn move_to(mut self, index: u64) -> Search {
+        let visible = self.config.get_visible_choices() as u64;
+
+        self.index = index;
+
+        if index < self.scroll_offset {
+            self.scroll_offset = index;
+        } else if visible > 0 && index >= self.scroll_offset + visible {
+            self.scroll_offset = index + 1 - visible;
+        }
+
+        self
     }
 
+    /// Insert the string at the cursor and leave the cursor after it.
+    pub fn append_search_string(mut self, string: &str) -> Search {
+        self.edit_query(|search| {
+            let offset = byte_offset(&search.query, search.cursor);
+            search.query.insert_str(offset, string);
+            search.cursor += string.chars().count();
+        });
+        self
+    }
+
+    /// Delete the char before the cursor.
     pub fn backspace(&mut self) -> &Search {
-        self.query.pop();
+        self.edit_query(|search| {
+            if search.cursor > 0 {
+                search.cursor -= 1;
+                search.remove_range(search.cursor, search.cursor + 1);
+            }
+        });
+        self
+    }
+
+    /// Delete the char under the cursor.
+    pub fn delete_forward(&mut self) -> &Search {
+        self.edit_query(|search| {
+            search.remove_range(search.cursor, search.cursor + 1);
+        });
         self
     }
 
     pub fn clear_query(&mut self) -> &Search {
-        self.query.truncate(0);
+        self.edit_query(|search| {
+            let killed = search.query.split_off(0);
+            search.kill(killed);
+            search.cursor = 0;
+        });
         self
     }
 
+    /// Kill the word before the cursor, along with any spaces between it
+    /// and the cursor.
     pub fn delete_word(&mut self) -> &Search {
-        let re = regex!(r"[^ ]* *$");
-        self.query = re.replace(self.query.as_slice(), "");
+        self.edit_query(|search| {
+            let end = search.cursor;
+            let start = search.word_start_before(end);
+            let killed = search.remove_range(start, end);
+            search.kill(killed);
+            search.cursor = start;
+        });
+        self
+    }
+
+    /// Kill from the cursor to the end of the query.
+    pub fn kill_to_end(&mut self) -> &Search {
+        self.edit_query(|search| {
+            let len = search.query.chars().count();
+            let killed = search.remove_range(search.cursor, len);
+            search.kill(killed);
+        });
+        self
+    }
+
+    /// Keep killed text for `yank`. Killing nothing keeps what was killed
+    /// before, as readline does.
+    fn kill(&mut self, killed: String) {
+        if !killed.is_empty() {
+            self.kill_buffer = killed;
+        }
+    }
+
+    /// Insert the most recently killed text at the cursor.
+    pub fn yank(self) -> Search {
+        let killed = self.kill_buffer.clone();
+        self.append_search_string(&killed)
+    }
+
+    /// Apply an edit to the query. Every change to the query must go
+    /// through here: if the edit changed the query, the matches are
+    /// recomputed and the selection goes back to the top.
+    fn edit_query<F>(&mut self, edit: F) where F: FnOnce(&mut Search) {
+        let previous = self.query.clone();
+
+        edit(self);
+
+        if self.query != previous {
+            let scored = compute_scored_matches(&self.choices, &self.parsed_query(), self.scorer());
+            (self.scores, self.ids, self.matches) = unzip_matches(scored);
+            self.index = 0;
+            self.scroll_offset = 0;
+        }
+    }
+
+    pub fn cursor_left(&mut self) -> &Search {
+        self.cursor = self.cursor.saturating_sub(1);
+        self
+    }
+
+    pub fn cursor_right(&mut self) -> &Search {
+        self.cursor = min(self.cursor + 1, self.query.chars().count());
+        self
+    }
+
+    pub fn cursor_home(&mut self) -> &Search {
+        self.cursor = 0;
+        self
+    }
+
+    pub fn cursor_end(&mut self) -> &Search {
+        self.cursor = self.query.chars().count();
+        self
+    }
+
+    /// Move to the start of the word before the cursor.
+    pub fn word_left(&mut self) -> &Search {
+        self.cursor = self.word_start_before(self.cursor);
+        self
+    }
+
+    /// Move to the end of the word after the cursor.
+    pub fn word_right(&mut self) -> &Search {
+        let chars: Vec<char> = self.query.chars().collect();
+        let mut i = self.cursor;
+
+        while i < chars.len() && chars[i] == ' ' {
+            i += 1;
+        }
+        while i < chars.len() && chars[i] != ' ' {
+            i += 1;
+        }
+
+        self.cursor = i;
         self
     }
 
+    fn word_start_before(&self, end: usize) -> usize {
+        let chars: Vec<char> = self.query.chars().collect();
+        let mut i = end;
+
+        while i > 0 && chars[i - 1] == ' ' {
+            i -= 1;
+        }
+        while i > 0 && chars[i - 1] != ' ' {
+            i -= 1;
+        }
+
+        i
+    }
+
+    /// Remove the chars in `start..end` from the query, clamped to its
+    /// length, and return them.
+    fn remove_range(&mut self, start: usize, end: usize) -> String {
+        let start = byte_offset(&self.query, start);
+        let end = byte_offset(&self.query, end);
+        self.query.drain(start..end).collect()
+    }
+
     pub fn selection(&self) -> Option<&String> {
         self.matches.get(self.index as usize)
     }
 
+    /// Mark the selected match, or unmark it if it's marked.
+    pub fn toggle_mark(&mut self) -> &Search {
+        if let Some(&id) = self.ids.get(self.index as usize) {
+            if !self.marked.remove(&id) {
+                self.marked.insert(id);
+            }
+        }
+        self
+    }
+
+    /// Mark every match for the current query.
+    pub fn mark_all(&mut self) -> &Search {
+        self.marked.extend(self.ids.iter().copied());
+        self
+    }
+
+    /// Unmark every match for the current query.
+    pub fn unmark_all(&mut self) -> &Search {
+        for id in &self.ids {
+            self.marked.remove(id);
+        }
+        self
+    }
+
+    /// Whether the match at `index` in the matches is marked.
+    pub fn is_marked(&self, index: u64) -> bool {
+        self.ids.get(index as usize).is_some_and(|id| self.marked.contains(id))
+    }
+
+    /// What to print when the search is done: the marked choices in input
+    /// order or, if none are marked, the selection.
+    pub fn selections(&self) -> Vec<&String> {
+        if self.marked.is_empty() {
+            return self.selection().into_iter().collect();
+        }
+
+        let mut marked: Vec<usize> = self.marked.iter().copied().collect();
+        marked.sort_unstable();
+        marked.iter().filter_map(|&id| self.choices.get(id)).collect()
+    }
+
+    pub fn is_multi(&self) -> bool {
+        self.config.get_multi()
+    }
+
+    /// The query as it's matched, in the extended syntax unless that's
+    /// turned off with `--no-extended`.
+    pub fn parsed_query(&self) -> Query {
+        Query::new(&self.query, self.config.get_extended())
+    }
+
+    /// How matches are scored, as picked with `--algorithm`.
+    pub fn scorer(&self) -> &'static dyn Scorer {
+        self.config.get_algorithm().scorer()
+    }
+
+    pub fn get_matches(&self) -> &Vec<String> {
+        &self.matches
+    }
+
+    /// The matches inside the visible window, starting at the scroll offset.
+    pub fn visible_matches(&self) -> &[String] {
+        let start = min(self.scroll_offset as usize, self.matches.len());
+        let end = min(start + self.get_visible_choices(), self.matches.len());
+        &self.matches[start..end]
+    }
+
+    pub fn get_scroll_offset(&self) -> u64 {
+        self.scroll_offset
+    }
+
+    /// How many choices there are to search, matching or not.
+    pub fn get_choice_count(&self) -> usize {
+        self.choices.len()
+    }
+
+    pub fn get_visible_choices(&self) -> usize {
+        self.config.get_visible_choices() as usize
+    }
+
     pub fn get_query(&self) -> &String {
         &self.query
     }
 
+    pub fn get_cursor(&self) -> usize {
+        self.cursor
+    }
+
     pub fn get_index(&self) -> u64 {
         self.index
     }
@@ -137,30 +471,87 @@ impl Search {
     }
 }
 
-fn compute_matches(choices: &Vec<String>, query: &str) -> Vec<String> {
-    choices.iter().map(|choice|
-        (choice, score(choice.as_slice(), query))
-    ).filter(|&(_choice, score)|
+/// Convert a char index into a byte offset in `s`, clamped to its length.
+fn byte_offset(s: &str, char_index: usize) -> usize {
+    s.char_indices().nth(char_index).map(|(i, _c)| i).unwrap_or(s.len())
+}
+
+/// Score every choice against the query and return the matches, best
+/// score first. Choices with equal scores keep their input order.
+pub fn compute_matches(choices: &[String], query: &Query, scorer: &dyn Scorer) -> Vec<String> {
+    compute_scored_matches(choices, query, scorer).into_iter().map(|(_score, _id, choice)| choice).collect()
+}
+
+/// `compute_matches`, keeping each match's score and its index in
+/// `choices`.
+fn compute_scored_matches(choices: &[String], query: &Query, scorer: &dyn Scorer) -> Vec<(f64, usize, String)> {
+    let mut scored: Vec<(f64, usize, &String)> = choices.iter().enumerate().map(|(id, choice)|
+        (query.score(choice, scorer), id, choice)
+    ).filter(|&(score, _id, _choice)|
         score > 0.0
-    ).map(|(choice, _score)| {
-        // we have to clone here unless we want to pass 
+    ).collect();
+
+    // sort_by is stable, which gives us the input order tiebreak
+    scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+
+    scored.into_iter().map(|(score, id, choice)| {
+        // we have to clone here unless we want to pass
         // the matches around as references everywhere.
-        choice.clone()
+        (score, id, choice.clone())
     }).collect()
 }
 
+/// Split scored matches into their scores, ids and text.
+fn unzip_matches(scored: Vec<(f64, usize, String)>) -> (Vec<f64>, Vec<usize>, Vec<String>) {
+    let mut scores = Vec::with_capacity(scored.len());
+    let mut ids = Vec::with_capacity(scored.len());
+    let mut matches = Vec::with_capacity(scored.len());
+    for (score, id, choice) in scored {
+        scores.push(score);
+        ids.push(id);
+        matches.push(choice);
+    }
+    (scores, ids, matches)
+}
+
+/// Merge matches for choices read later into the existing ones, both best
+/// first. On equal scores the existing match goes first, as it came first
+/// in the input. Also returns where the existing match at `selected` ends
+/// up.
+fn merge_matches<I>(old: I, new: Vec<(f64, usize, String)>, selected: usize) -> (Vec<(f64, usize, String)>, usize)
+    where I: ExactSizeIterator<Item = (f64, usize, String)>
+{
+    let mut merged = Vec::with_capacity(old.len() + new.len());
+    let mut new = new.into_iter().peekable();
+    let mut moved_to = 0;
+
+    for (i, (score, id, choice)) in old.enumerate() {
+        while let Some(next) = new.next_if(|&(new_score, _, _)| new_score > score) {
+            merged.push(next);
+        }
+        if i == selected {
+            moved_to = merged.len();
+        }
+        merged.push((score, id, choice));
+    }
+    merged.extend(new);
+
+    (merged, moved_to)
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
-    use config::Configuration;
+    use crate::config::Configuration;
+    use crate::options::Options;
 
     fn get_test_config() -> Configuration {
-        let choices: Vec<String> = vec!(String::from_str("one"),
-        String::from_str("two"),
-        String::from_str("three"));
+        let choices: Vec<String> = vec!(String::from("one"),
+        String::from("two"),
+        String::from("three"));
 
-        Configuration::from_inputs(choices, 21)
+        Configuration::from_inputs(choices, &Options::default(), 21)
     }
 
     fn get_blank_search() -> Search {
@@ -176,60 +567,138 @@ mod tests {
     #[test]
     fn test_selects_first_choice() {
         let search = get_blank_search();
-        assert!(search.selection().unwrap().as_slice() == "one");
+        assert!(search.selection().unwrap().as_str() == "one");
     }
 
     #[test]
     fn test_moves_down_list() {
         let search = get_blank_search();
-        assert!(search.down().selection().unwrap().as_slice() == "two");
+        assert!(search.down().selection().unwrap().as_str() == "two");
     }
 
     #[test]
     fn test_moves_up() {
         let search = get_blank_search();
-        assert!(search.down().up().selection().unwrap().as_slice() == "one");
+        assert!(search.down().up().selection().unwrap().as_str() == "one");
     }
 
     #[test]
     fn test_loops_around_when_reaching_end_of_the_list() {
         let search = get_blank_search();
-        assert!(search.down().down().down().down().selection().unwrap().as_slice() == "two");
+        assert!(search.down().down().down().down().selection().unwrap().as_str() == "two");
     }
 
     #[test]
     fn test_loops_around_when_reaching_top_of_the_list() {
         let search = get_blank_search();
-        assert!(search.up().up().selection().unwrap().as_slice() == "two");
+        assert!(search.up().up().selection().unwrap().as_str() == "two");
+    }
+
+    fn get_numbered_search(count: usize, visible_choices: u8, wraparound: bool) -> Search {
+        let choices = (0..count).map(|i| i.to_string()).collect();
+        let options = Options { wraparound, ..Options::default() };
+        let config = Configuration::from_inputs(choices, &options, visible_choices as u16 + 1);
+        Search::blank(config)
     }
 
     #[test]
-    fn test_loops_around_when_reaching_visible_choice_limit() {
-        let choices: Vec<String> = vec!(String::from_str("one"),
-        String::from_str("two"),
-        String::from_str("three"));
+    fn test_scrolls_past_visible_choice_limit() {
+        let choices: Vec<String> = vec!(String::from("one"),
+        String::from("two"),
+        String::from("three"));
 
         let config = Configuration::new(2, "".to_string(), choices);
-        let search = Search::blank(config);
-        assert!(search.down().down().down().selection().unwrap().as_slice() == "two");
+        let search = Search::blank(config).down().down();
+        assert!(search.selection().unwrap().as_str() == "three");
+        assert!(search.get_scroll_offset() == 1);
+        assert!(search.visible_matches() == ["two".to_string(), "three".to_string()]);
+    }
+
+    #[test]
+    fn test_can_reach_every_match() {
+        let mut search = get_numbered_search(50, 20, true);
+        for _ in 0..49 {
+            search = search.down();
+        }
+        assert!(search.selection().unwrap().as_str() == "49");
+        assert!(search.get_scroll_offset() == 30);
+        assert!(search.down().selection().unwrap().as_str() == "0");
+    }
+
+    #[test]
+    fn test_window_follows_cursor_up() {
+        let search = get_numbered_search(50, 20, true).up();
+        assert!(search.selection().unwrap().as_str() == "49");
+        assert!(search.get_scroll_offset() == 30);
+
+        let mut search = search;
+        for _ in 0..20 {
+            search = search.up();
+        }
+        assert!(search.selection().unwrap().as_str() == "29");
+        assert!(search.get_scroll_offset() == 29);
+    }
+
+    #[test]
+    fn test_stops_at_ends_without_wraparound() {
+        let search = get_numbered_search(3, 20, false);
+        assert!(search.up().selection().unwrap().as_str() == "0");
+
+        let search = get_numbered_search(3, 20, false);
+        assert!(search.down().down().down().selection().unwrap().as_str() == "2");
+    }
+
+    #[test]
+    fn test_pages_down_and_up() {
+        let search = get_numbered_search(50, 20, true).page_down();
+        assert!(search.selection().unwrap().as_str() == "20");
+        assert!(search.get_scroll_offset() == 1);
+
+        let search = search.page_down().page_down();
+        assert!(search.selection().unwrap().as_str() == "49");
+
+        let search = search.page_up();
+        assert!(search.selection().unwrap().as_str() == "29");
+        assert!(search.get_scroll_offset() == 29);
+
+        let search = search.page_up().page_up();
+        assert!(search.selection().unwrap().as_str() == "0");
+        assert!(search.get_scroll_offset() == 0);
+    }
+
+    #[test]
+    fn test_moves_to_first_and_last() {
+        let search = get_numbered_search(50, 20, true).last();
+        assert!(search.selection().unwrap().as_str() == "49");
+        assert!(search.get_scroll_offset() == 30);
+
+        let search = search.first();
+        assert!(search.selection().unwrap().as_str() == "0");
+        assert!(search.get_scroll_offset() == 0);
+    }
+
+    #[test]
+    fn test_everything_filtered_out_cannot_page_or_jump() {
+        let search = get_blank_search().append_search_string("zzz");
+        assert!(search.page_down().page_up().last().first().selection().is_none());
     }
 
     #[test]
     fn test_filtered_search_results_moves_up_down_list() {
         let search = get_blank_search().append_search_string("t");
-        assert!(search.down().selection().unwrap().as_slice() == "three");
+        assert!(search.down().selection().unwrap().as_str() == "three");
 
         let search = get_blank_search().append_search_string("t");
-        assert!(search.up().selection().unwrap().as_slice() == "three");
+        assert!(search.up().selection().unwrap().as_str() == "three");
     }
 
     #[test]
     fn test_filtered_search_results_loops_around_when_reaching_the_end() {
         let search = get_blank_search().append_search_string("t");
-        assert!(search.down().down().selection().unwrap().as_slice() == "two");
+        assert!(search.down().down().selection().unwrap().as_str() == "two");
 
         let search = get_blank_search().append_search_string("t");
-        assert!(search.up().selection().unwrap().as_slice() == "three");
+        assert!(search.up().selection().unwrap().as_str() == "three");
     }
 
     #[test]
@@ -252,20 +721,20 @@ mod tests {
         let mut search = get_blank_search();
         search = search.append_search_string("e");
 
-        assert!(search.get_query().as_slice() == "e");
-        assert!(search.backspace().get_query().as_slice() == "");
+        assert!(search.get_query().as_str() == "e");
+        assert!(search.backspace().get_query().as_str() == "");
         assert!(search.get_index() == 0);
 
     }
 
     #[test]
     fn test_deletes_words() {
-        assert!(get_blank_search().append_search_string("").delete_word().get_query().as_slice() == "");
-        assert!(get_blank_search().append_search_string("a").delete_word().get_query().as_slice() == "");
-        assert!(get_blank_search().append_search_string("a ").delete_word().get_query().as_slice() == "");
-        assert!(get_blank_search().append_search_string("a b").delete_word().get_query().as_slice() == "a ");
-        assert!(get_blank_search().append_search_string("a b ").delete_word().get_query().as_slice() == "a ");
-        assert!(get_blank_search().append_search_string(" a b").delete_word().get_query().as_slice() == " a ");
+        assert!(get_blank_search().append_search_string("").delete_word().get_query().as_str() == "");
+        assert!(get_blank_search().append_search_string("a").delete_word().get_query().as_str() == "");
+        assert!(get_blank_search().append_search_string("a ").delete_word().get_query().as_str() == "");
+        assert!(get_blank_search().append_search_string("a b").delete_word().get_query().as_str() == "a ");
+        assert!(get_blank_search().append_search_string("a b ").delete_word().get_query().as_str() == "a ");
+        assert!(get_blank_search().append_search_string(" a b").delete_word().get_query().as_str() == " a ");
     }
 
     #[test]
@@ -274,22 +743,411 @@ mod tests {
 
         for query in given_queries.iter() {
             let search = get_blank_search();
-            assert!(search.append_search_string(*query).clear_query().get_query().as_slice() == "");
+            assert!(search.append_search_string(query).clear_query().get_query().as_str() == "");
         }
     }
 
+    fn search_for(choices: &[&str], query: &str) -> Search {
+        let choices = choices.iter().map(|c| c.to_string()).collect();
+        let config = Configuration::from_inputs(choices, &Options::default(), 21);
+        Search::blank(config).append_search_string(query)
+    }
+
     #[test]
     fn test_only_returns_matching_choices() {
+        let search = search_for(&["a", "b"], "a");
+        assert!(*search.get_matches() == vec!("a".to_string()));
     }
 
     #[test]
     fn test_sorts_the_choices_by_score() {
+        let search = search_for(&["spec/search_spec.rb", "search.rb"], "search");
+        assert!(*search.get_matches() == vec!("search.rb".to_string(),
+                                              "spec/search_spec.rb".to_string()));
+    }
+
+    #[test]
+    fn test_keeps_input_order_for_equal_scores() {
+        let search = search_for(&["bca", "abc", "cab"], "");
+        assert!(*search.get_matches() == vec!("bca".to_string(),
+                                              "abc".to_string(),
+                                              "cab".to_string()));
+
+        let search = search_for(&["xa", "ya", "za", "a"], "a");
+        assert!(*search.get_matches() == vec!("a".to_string(),
+                                              "xa".to_string(),
+                                              "ya".to_string(),
+                                              "za".to_string()));
     }
 
     #[test]
     fn test_knows_when_done() {
         let mut search = get_blank_search();
-        assert!(search.is_done() == false);
-        assert!(search.done().is_done() == true);
+        assert!(!search.is_done());
+        assert!(search.done().is_done());
+    }
+
+    fn search_with_cursor(query: &str, cursor: usize) -> Search {
+        let mut search = get_blank_search().append_search_string(query);
+        search.cursor_home();
+        for _ in 0..cursor {
+            search.cursor_right();
+        }
+        search
+    }
+
+    #[test]
+    fn test_cursor_starts_at_end_of_initial_search() {
+        let choices = vec!("one".to_string());
+        let config = Configuration::new(20, "héllo".to_string(), choices);
+        assert!(Search::blank(config).get_cursor() == 5);
+    }
+
+    #[test]
+    fn test_moves_cursor_left_and_right() {
+        let mut search = get_blank_search().append_search_string("ab");
+        assert!(search.get_cursor() == 2);
+        assert!(search.cursor_left().get_cursor() == 1);
+        assert!(search.cursor_left().get_cursor() == 0);
+        assert!(search.cursor_left().get_cursor() == 0);
+        assert!(search.cursor_right().get_cursor() == 1);
+        assert!(search.cursor_right().get_cursor() == 2);
+        assert!(search.cursor_right().get_cursor() == 2);
+    }
+
+    #[test]
+    fn test_moves_cursor_home_and_end() {
+        let mut search = get_blank_search().append_search_string("abc");
+        assert!(search.cursor_home().get_cursor() == 0);
+        assert!(search.cursor_end().get_cursor() == 3);
+    }
+
+    #[test]
+    fn test_moves_by_words() {
+        let mut search = get_blank_search().append_search_string("foo  bar baz");
+        assert!(search.word_left().get_cursor() == 9);
+        assert!(search.word_left().get_cursor() == 5);
+        assert!(search.word_left().get_cursor() == 0);
+        assert!(search.word_left().get_cursor() == 0);
+        assert!(search.word_right().get_cursor() == 3);
+        assert!(search.word_right().get_cursor() == 8);
+        assert!(search.word_right().get_cursor() == 12);
+        assert!(search.word_right().get_cursor() == 12);
+    }
+
+    #[test]
+    fn test_inserts_at_cursor() {
+        let search = search_with_cursor("ac", 1).append_search_string("b");
+        assert!(search.get_query().as_str() == "abc");
+        assert!(search.get_cursor() == 2);
+
+        let search = search_with_cursor("éü", 1).append_search_string("ß");
+        assert!(search.get_query().as_str() == "éßü");
+    }
+
+    #[test]
+    fn test_backspaces_at_cursor() {
+        let mut search = search_with_cursor("abc", 2);
+        assert!(search.backspace().get_query().as_str() == "ac");
+        assert!(search.get_cursor() == 1);
+
+        let mut search = search_with_cursor("abc", 0);
+        assert!(search.backspace().get_query().as_str() == "abc");
+    }
+
+    #[test]
+    fn test_deletes_forward() {
+        let mut search = search_with_cursor("abc", 1);
+        assert!(search.delete_forward().get_query().as_str() == "ac");
+        assert!(search.get_cursor() == 1);
+
+        let mut search = search_with_cursor("abc", 3);
+        assert!(search.delete_forward().get_query().as_str() == "abc");
+    }
+
+    #[test]
+    fn test_deletes_word_before_cursor() {
+        let mut search = search_with_cursor("foo bar baz", 7);
+        assert!(search.delete_word().get_query().as_str() == "foo  baz");
+        assert!(search.get_cursor() == 4);
+    }
+
+    #[test]
+    fn test_kills_to_end() {
+        let mut search = search_with_cursor("foo bar", 3);
+        assert!(search.kill_to_end().get_query().as_str() == "foo");
+        assert!(search.get_cursor() == 3);
+    }
+
+    #[test]
+    fn test_yanks_last_killed_text() {
+        let mut search = search_with_cursor("foo bar", 3);
+        search.kill_to_end();
+        search.cursor_home();
+        let search = search.yank();
+        assert!(search.get_query().as_str() == " barfoo");
+        assert!(search.get_cursor() == 4);
+
+        let mut search = search.yank();
+        search.delete_word();
+        let search = search.yank();
+        assert!(search.get_query().as_str() == " bar barfoo");
+
+        // killing nothing leaves the last kill to yank
+        let mut search = search_with_cursor("foo", 0);
+        search.kill_to_end();
+        search.delete_word();
+        search.kill_to_end();
+        search.clear_query();
+        let search = search.yank();
+        assert!(search.get_query().as_str() == "foo");
+    }
+
+    #[test]
+    fn test_yank_with_nothing_killed_does_nothing() {
+        let search = get_blank_search().append_search_string("abc").yank();
+        assert!(search.get_query().as_str() == "abc");
+    }
+
+    #[test]
+    fn test_every_query_edit_recomputes_matches() {
+        let one = vec!("one".to_string());
+        let all = vec!("one".to_string(), "two".to_string(), "three".to_string());
+
+        let mut search = get_blank_search().append_search_string("on");
+        assert!(*search.get_matches() == one);
+        assert!(*search.backspace().get_matches() == vec!("one".to_string(), "two".to_string()));
+
+        let mut search = get_blank_search().append_search_string("on");
+        assert!(*search.clear_query().get_matches() == all);
+
+        let mut search = get_blank_search().append_search_string("zz");
+        assert!(search.get_matches().is_empty());
+        assert!(*search.delete_word().get_matches() == all);
+
+        let mut search = get_blank_search().append_search_string("onx");
+        search.cursor_left();
+        assert!(*search.delete_forward().get_matches() == one);
+
+        let mut search = get_blank_search().append_search_string("onx");
+        search.cursor_left();
+        assert!(*search.kill_to_end().get_matches() == one);
+
+        let search = search.yank();
+        assert!(search.get_matches().is_empty());
+    }
+
+    #[test]
+    fn test_every_query_edit_resets_index() {
+        let mut search = get_blank_search().down().down();
+        assert!(search.get_index() == 2);
+
+        search.clear_query();
+        assert!(search.get_index() == 2);
+
+        let mut search = search.append_search_string("e").down();
+        assert!(search.get_index() == 1);
+        assert!(search.backspace().get_index() == 0);
+    }
+
+    #[test]
+    fn test_cursor_movement_keeps_index() {
+        let mut search = get_blank_search().append_search_string("e").down();
+        search.cursor_left();
+        search.cursor_home();
+        assert!(search.get_index() == 1);
+    }
+
+    #[test]
+    fn test_resize_changes_visible_choices() {
+        let search = get_numbered_search(50, 20, true).resize(11);
+        assert!(search.get_visible_choices() == 10);
+        assert!(search.visible_matches().len() == 10);
+
+        let search = search.resize(100);
+        assert!(search.get_visible_choices() == 20);
+    }
+
+    #[test]
+    fn test_resize_keeps_selection_visible() {
+        let mut search = get_numbered_search(50, 20, true);
+        for _ in 0..15 {
+            search = search.down();
+        }
+        assert!(search.get_scroll_offset() == 0);
+
+        let search = search.resize(6);
+        assert!(search.selection().unwrap().as_str() == "15");
+        assert!(search.get_scroll_offset() == 11);
+    }
+
+    #[test]
+    fn test_resize_does_not_leave_blank_rows() {
+        let search = get_numbered_search(50, 5, true).last();
+        assert!(search.get_scroll_offset() == 45);
+
+        let search = search.resize(21);
+        assert!(search.selection().unwrap().as_str() == "49");
+        assert!(search.get_scroll_offset() == 30);
+        assert!(search.visible_matches().len() == 20);
+    }
+
+    #[test]
+    fn test_resize_to_nothing_does_not_crash() {
+        let search = get_numbered_search(50, 20, true).last().resize(0);
+        assert!(search.get_visible_choices() == 0);
+        assert!(search.visible_matches().is_empty());
+        assert!(search.resize(21).selection().unwrap().as_str() == "49");
+    }
+
+    fn get_multi_search(choices: &[&str]) -> Search {
+        let choices = choices.iter().map(|c| c.to_string()).collect();
+        let options = Options { multi: true, ..Options::default() };
+        Search::blank(Configuration::from_inputs(choices, &options, 21))
+    }
+
+    #[test]
+    fn test_toggles_marks() {
+        let mut search = get_multi_search(&["one", "two", "three"]).down();
+        assert!(search.is_multi());
+        assert!(search.toggle_mark().is_marked(1));
+        assert!(!search.is_marked(0));
+        assert!(!search.toggle_mark().is_marked(1));
+    }
+
+    #[test]
+    fn test_marks_stay_on_choices_when_the_query_changes() {
+        let mut search = get_multi_search(&["one", "two", "three"]).down();
+        search.toggle_mark();
+
+        let mut search = search.append_search_string("t");
+        assert!(search.get_matches() == &vec!("two".to_string(), "three".to_string()));
+        assert!(search.is_marked(0));
+
+        search.backspace();
+        assert!(search.is_marked(1));
+        assert!(search.selections() == vec!("two"));
+    }
+
+    #[test]
+    fn test_marks_and_unmarks_all_matches() {
+        let mut search = get_multi_search(&["one", "two", "three"]).append_search_string("t");
+        search.mark_all();
+        assert!(search.is_marked(0) && search.is_marked(1));
+
+        search.clear_query();
+        assert!(!search.is_marked(0));
+        search.toggle_mark();
+        search.unmark_all();
+        assert!(search.selections() == vec!("one"));
+    }
+
+    #[test]
+    fn test_marks_duplicate_lines_separately() {
+        let mut search = get_multi_search(&["a", "a", "b"]);
+        search.toggle_mark();
+        assert!(search.is_marked(0));
+        assert!(!search.is_marked(1));
+        assert!(search.selections() == vec!("a"));
+
+        let mut search = search.append_search_string("a");
+        search.backspace();
+        assert!(search.is_marked(0) && !search.is_marked(1));
+    }
+
+    #[test]
+    fn test_marks_choices_added_later_by_their_own_ids() {
+        let search = get_multi_search(&["a"]).add_choices(vec!("a".to_string()));
+        let mut search = search.last();
+        search.toggle_mark();
+        assert!(!search.is_marked(0) && search.is_marked(1));
+    }
+
+    #[test]
+    fn test_gives_given_matches_the_ids_of_their_choices() {
+        let choices: Vec<String> = ["a", "b", "a"].iter().map(|c| c.to_string()).collect();
+        let options = Options { multi: true, ..Options::default() };
+        let config = Configuration::from_inputs(choices.clone(), &options, 21);
+        let matches = ["a", "x", "a", "a"].iter().map(|c| c.to_string()).collect();
+        let mut search = Search::new(config, choices, 1, String::new(), false, Some(matches));
+
+        assert!(search.get_matches() == &vec!("a".to_string(), "a".to_string()));
+        search.toggle_mark();
+        assert!(search.selections() == vec!("a"));
+        assert!(!search.is_marked(0) && search.is_marked(1));
+    }
+
+    #[test]
+    fn test_selections_are_marked_choices_in_input_order() {
+        let mut search = get_multi_search(&["one", "two", "three"]).last();
+        search.toggle_mark();
+        let mut search = search.first();
+        search.toggle_mark();
+        assert!(search.selections() == vec!("one", "three"));
+    }
+
+    #[test]
+    fn test_selections_fall_back_to_the_selection() {
+        let search = get_multi_search(&["one", "two", "three"]).down();
+        assert!(search.selections() == vec!("two"));
+
+        let search = get_multi_search(&["one"]).append_search_string("x");
+        assert!(search.selections().is_empty());
+    }
+
+    #[test]
+    fn test_matches_queries_as_typed_without_extended_syntax() {
+        let choices = vec!("foo bar".to_string(), "!foo".to_string(), "foobar".to_string());
+        let options = Options { extended: false, ..Options::default() };
+        let search = Search::blank(Configuration::from_inputs(choices.clone(), &options, 21));
+        assert!(search.append_search_string("!foo").get_matches() == &vec!("!foo".to_string()));
+
+        let search = Search::blank(Configuration::from_inputs(choices, &options, 21));
+        assert!(search.append_search_string("o b").get_matches() == &vec!("foo bar".to_string()));
+    }
+
+    #[test]
+    fn test_adds_choices_as_they_arrive() {
+        let config = Configuration::from_inputs(Vec::new(), &Options::default(), 21);
+        let search = Search::blank(config).append_search_string("t");
+        assert!(search.selection().is_none());
+
+        let search = search.add_choices(vec!("one".to_string(), "two".to_string()));
+        assert!(search.get_matches() == &vec!("two".to_string()));
+
+        let search = search.add_choices(vec!("three".to_string(), "t".to_string()));
+        assert!(search.get_matches() == &vec!("t".to_string(), "two".to_string(), "three".to_string()));
+    }
+
+    #[test]
+    fn test_adding_choices_keeps_the_selection() {
+        let search = get_numbered_search(10, 3, true).down();
+        assert!(search.selection().unwrap() == "1");
+
+        let search = search.add_choices(vec!("10".to_string(), "11".to_string()));
+        assert!(search.selection().unwrap() == "1");
+        assert!(search.get_matches().len() == 12);
+
+        let search = search.append_search_string("1");
+        let search = search.last();
+        assert!(search.selection().unwrap() == "11");
+        assert!(search.get_index() == 2);
+        assert!(search.get_scroll_offset() == 0);
+
+        // better matches go above the selection, which stays on its row
+        let search = search.add_choices(vec!("a1".to_string(), "1".to_string(), "1".to_string()));
+        assert!(search.get_matches()[..3] == ["1".to_string(), "1".to_string(), "1".to_string()]);
+        assert!(search.selection().unwrap() == "11");
+        assert!(search.get_index() == 4);
+        assert!(search.get_scroll_offset() == 2);
+    }
+
+    #[test]
+    fn test_adding_choices_keeps_marks() {
+        let mut search = get_multi_search(&["one", "two"]);
+        search.toggle_mark();
+        let search = search.add_choices(vec!("zero".to_string()));
+        assert!(search.selections() == vec!("one"));
+        assert!(search.selection().unwrap() == "one");
     }
 }
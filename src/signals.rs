@@ -0,0 +1,74 @@
+use std::io;
+use std::mem;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+
+use libc::{c_int, raise, sigaction, sigemptyset, sighandler_t, signal, SIGHUP, SIGINT, SIGTERM, SIGWINCH, SIG_DFL};
+
+static RESIZED: AtomicBool = AtomicBool::new(false);
+
+/// The last termination signal received, or 0.
+static TERMINATION: AtomicI32 = AtomicI32::new(0);
+
+extern "C" fn handle_sigwinch(_signal: c_int) {
+    RESIZED.store(true, Ordering::SeqCst);
+}
+
+extern "C" fn handle_termination(signal: c_int) {
+    TERMINATION.store(signal, Ordering::SeqCst);
+}
+
+/// Note terminal resizes (SIGWINCH) so the picker can redraw.
+///
+/// The handler is installed without SA_RESTART, so a read blocked on the
+/// tty returns `ErrorKind::Interrupted` when the window changes size.
+pub fn install_resize_handler() -> io::Result<()> {
+    install(SIGWINCH, handle_sigwinch)
+}
+
+/// Whether the terminal was resized since the last call.
+pub fn take_resized() -> bool {
+    RESIZED.swap(false, Ordering::SeqCst)
+}
+
+/// Catch SIGINT, SIGTERM and SIGHUP instead of dying on the spot, so the
+/// picker can unwind and restore the terminal. Like SIGWINCH, they
+/// interrupt a blocked read.
+pub fn install_termination_handlers() -> io::Result<()> {
+    for &signal in [SIGINT, SIGTERM, SIGHUP].iter() {
+        install(signal, handle_termination)?;
+    }
+    Ok(())
+}
+
+/// Whether a termination signal has arrived.
+pub fn termination_requested() -> bool {
+    TERMINATION.load(Ordering::SeqCst) != 0
+}
+
+/// If a termination signal arrived, die from it now with the default
+/// action, so our parent sees the usual exit status.
+pub fn reraise_termination() {
+    let received = TERMINATION.load(Ordering::SeqCst);
+
+    if received != 0 {
+        unsafe {
+            signal(received, SIG_DFL);
+            raise(received);
+        }
+    }
+}
+
+fn install(signal: c_int, handler: extern "C" fn(c_int)) -> io::Result<()> {
+    unsafe {
+        let mut action: sigaction = mem::zeroed();
+        action.sa_sigaction = handler as sighandler_t;
+        action.sa_flags = 0;
+        sigemptyset(&mut action.sa_mask);
+
+        match sigaction(signal, &action, ptr::null_mut()) {
+            0 => Ok(()),
+            _ => Err(io::Error::last_os_error()),
+        }
+    }
+}
@@ -1,54 +1,249 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::mem;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::time::Duration;
 
-use std::os::unix::prelude::AsRawFd;
-use std::io::{File, FileMode, FileAccess, IoResult, standard_error, ResourceUnavailable};
-use std::io::stdio::{stdin, StdinReader, stdout_raw, StdWriter};
-use std::io::pipe::{PipePair, PipeStream};
-use std::io::Command;
-use std::io::process::StdioContainer;
+use libc::{poll, pollfd, tcgetattr, tcsetattr, termios, write, ECHO, ICANON, IEXTEN, IXON, POLLIN, TCSAFLUSH, VMIN, VTIME};
 
-use winsize;
+use crate::key::{Decoder, KeyEvent};
+use crate::winsize;
+
+/// How long to wait after an ESC for the rest of an escape sequence before
+/// deciding the Escape key was pressed on its own.
+const ESCAPE_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// How long to wait for the terminal to answer a cursor position request.
+const REPORT_TIMEOUT: Duration = Duration::from_secs(1);
 
 pub struct TTY {
     in_file: File,
     out_file: File,
+    decoder: Decoder,
+    /// The terminal settings in place before we changed anything, so they
+    /// can be put back on the way out.
+    original_termios: termios,
 }
 
 impl TTY {
-    pub fn new() -> IoResult<TTY> {
-        let path = Path::new("/dev/tty");
-        let in_file = File::open_mode(&path, FileMode::Open, FileAccess::Write).unwrap();
-        let out_file = File::open(&path).unwrap();
+    pub fn new() -> io::Result<TTY> {
+        let in_file = File::open("/dev/tty")?;
+        let out_file = OpenOptions::new().write(true).open("/dev/tty")?;
+        let original_termios = get_termios(&in_file)?;
+
+        Ok(TTY { in_file, out_file, decoder: Decoder::new(), original_termios })
+    }
+
+    /// Read the next key from the terminal, decoding escape sequences.
+    ///
+    /// A signal arriving while we wait shows up as `ErrorKind::Interrupted`.
+    pub fn get_key(&mut self) -> io::Result<KeyEvent> {
+        loop {
+            if let Some(key) = self.get_key_timeout(None)? {
+                return Ok(key);
+            }
+        }
+    }
+
+    /// Read the next key like `get_key`, but give up with `None` if
+    /// nothing is typed before the timeout.
+    pub fn get_key_timeout(&mut self, timeout: Option<Duration>) -> io::Result<Option<KeyEvent>> {
+        loop {
+            if let Some(key) = self.decoder.next_key() {
+                return Ok(Some(key));
+            }
+
+            // Part of a sequence is buffered; if nothing follows it soon,
+            // take it as typed.
+            let pending = self.decoder.has_pending();
+            let wait = if pending { Some(ESCAPE_TIMEOUT) } else { timeout };
 
-        Ok(TTY { in_file: in_file, out_file: out_file })
+            if !self.wait_for_input(wait)? {
+                if !pending {
+                    return Ok(None);
+                }
+                if let Some(key) = self.decoder.flush() {
+                    return Ok(Some(key));
+                }
+                continue;
+            }
+
+            let mut buf = [0u8; 64];
+            match self.in_file.read(&mut buf)? {
+                0 => return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "the terminal was closed")),
+                n => self.decoder.push(&buf[..n]),
+            }
+        }
     }
 
-    pub fn get_char(&mut self) -> char {
-        match self.in_file.read_byte() {
-            Ok(c) => c as char,
-            Err(e) => {
-                // log
-                ' '
+    /// Ask the terminal where the cursor is, as a zero-based (line, column).
+    ///
+    /// Keys typed while we wait for the answer are kept for `get_key`.
+    pub fn cursor_position(&mut self) -> io::Result<(u16, u16)> {
+        self.out_file.write_all(b"\x1b[6n")?;
+        self.out_file.flush()?;
+
+        let mut input = Vec::new();
+        loop {
+            if let Some((start, end, position)) = find_cursor_report(&input) {
+                self.decoder.push(&input[..start]);
+                self.decoder.push(&input[end..]);
+                return Ok(position);
+            }
+
+            if !self.wait_for_input(Some(REPORT_TIMEOUT))? {
+                self.decoder.push(&input);
+                return Err(io::Error::new(io::ErrorKind::TimedOut,
+                                          "the terminal didn't report the cursor position"));
             }
+
+            let mut buf = [0u8; 64];
+            match self.in_file.read(&mut buf)? {
+                0 => return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "the terminal was closed")),
+                n => input.extend_from_slice(&buf[..n]),
+            }
+        }
+    }
+
+    /// Wait until the terminal has input, or the timeout passes. Returns
+    /// whether input is ready.
+    fn wait_for_input(&mut self, timeout: Option<Duration>) -> io::Result<bool> {
+        let mut fds = pollfd { fd: self.in_file.as_raw_fd(), events: POLLIN, revents: 0 };
+        let timeout_ms = timeout.map(|t| t.as_millis() as i32).unwrap_or(-1);
+
+        match unsafe { poll(&mut fds, 1, timeout_ms) } {
+            -1 => Err(io::Error::last_os_error()),
+            0 => Ok(false),
+            _ => Ok(true),
         }
     }
 
+    /// A second handle on the terminal for drawing, so the UI never goes
+    /// through stdout.
+    pub fn output(&self) -> io::Result<File> {
+        self.out_file.try_clone()
+    }
+
     pub fn puts(&mut self) {
-        let _ = self.out_file.write_line("");
+        let _ = writeln!(self.out_file);
     }
 
-    pub fn winsize(&mut self) -> IoResult<(u16, u16)> {
-        winsize::winsize()
+    pub fn winsize(&mut self) -> io::Result<(u16, u16)> {
+        winsize::winsize(self.out_file.as_raw_fd())
     }
 
-    pub fn stty(&mut self, arg: &str) -> String {
-        let path = Path::new("/dev/tty");
-        let file = File::open(&path).unwrap();
-        let tty_fd = file.as_raw_fd();
-        let output = match Command::new("stty").arg(arg).stdin(StdioContainer::InheritFd(tty_fd)).output() {
-            Ok(o) => o,
-            Err(e) => panic!("failed to execute process: {}", e),
-        };
+    /// Put the terminal in cbreak mode: keys are delivered one at a time
+    /// (-icanon) and aren't echoed back (-echo).
+    ///
+    /// The keys the terminal would otherwise keep for itself are passed
+    /// through too: Ctrl-S and Ctrl-Q (-ixon), Ctrl-V (-iexten) and, on
+    /// the BSDs, Ctrl-Y (VDSUSP). Ctrl-C still sends SIGINT.
+    pub fn configure(&mut self) -> io::Result<()> {
+        let mut t = self.original_termios;
+        t.c_lflag &= !(ECHO | ICANON | IEXTEN);
+        t.c_iflag &= !IXON;
+        t.c_cc[VMIN] = 1;
+        t.c_cc[VTIME] = 0;
+        #[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd",
+                  target_os = "dragonfly", target_os = "openbsd", target_os = "netbsd"))]
+        {
+            t.c_cc[libc::VDSUSP] = libc::_POSIX_VDISABLE;
+        }
+        set_termios(self.in_file.as_raw_fd(), &t)
+    }
+
+    /// Put back the terminal settings we found in `new`.
+    pub fn restore(&mut self) -> io::Result<()> {
+        set_termios(self.in_file.as_raw_fd(), &self.original_termios)
+    }
+
+    /// What it takes to put the terminal back, in a form that doesn't
+    /// need the `TTY` itself, e.g. from a panic hook.
+    ///
+    /// It refers to this `TTY`'s file descriptors, so it must not be used
+    /// once the `TTY` is dropped.
+    pub fn saved_state(&self) -> SavedState {
+        SavedState { in_fd: self.in_file.as_raw_fd(),
+                     out_fd: self.out_file.as_raw_fd(),
+                     termios: self.original_termios }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct SavedState {
+    in_fd: RawFd,
+    out_fd: RawFd,
+    termios: termios,
+}
+
+impl SavedState {
+    /// Write `cleanup` to the terminal, then restore its original settings.
+    /// Errors are ignored: this runs on the way out, when there is nothing
+    /// left to do about them.
+    pub fn restore(&self, cleanup: &[u8]) {
+        unsafe {
+            write(self.out_fd, cleanup.as_ptr() as *const _, cleanup.len());
+        }
+        let _ = set_termios(self.in_fd, &self.termios);
+    }
+}
+
+/// Find a cursor position report, `ESC [ line ; column R`, in `input`.
+/// Returns where it starts and ends, and the position it holds, made
+/// zero-based.
+fn find_cursor_report(input: &[u8]) -> Option<(usize, usize, (u16, u16))> {
+    (0..input.len()).filter(|&i| input[i..].starts_with(b"\x1b[")).find_map(|start| {
+        let params = &input[start + 2..];
+        let len = params.iter().position(|&b| !(b.is_ascii_digit() || b == b';'))?;
+        if params[len] != b'R' {
+            return None;
+        }
+
+        let params = std::str::from_utf8(&params[..len]).ok()?;
+        let (line, column) = params.split_once(';')?;
+        let line = line.parse::<u16>().ok()?.checked_sub(1)?;
+        let column = column.parse::<u16>().ok()?.checked_sub(1)?;
+        Some((start, start + 2 + len + 1, (line, column)))
+    })
+}
+
+fn get_termios(file: &File) -> io::Result<termios> {
+    let mut t: termios = unsafe { mem::zeroed() };
+    match unsafe { tcgetattr(file.as_raw_fd(), &mut t) } {
+        0 => Ok(t),
+        _ => Err(io::Error::last_os_error()),
+    }
+}
+
+fn set_termios(fd: RawFd, t: &termios) -> io::Result<()> {
+    match unsafe { tcsetattr(fd, TCSAFLUSH, t) } {
+        0 => Ok(()),
+        _ => Err(io::Error::last_os_error()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_finds_cursor_reports() {
+        assert_eq!(find_cursor_report(b"\x1b[12;5R"), Some((0, 7, (11, 4))));
+        assert_eq!(find_cursor_report(b"ab\x1b[1;1Rc"), Some((2, 8, (0, 0))));
+    }
+
+    #[test]
+    fn test_skips_keys_around_cursor_reports() {
+        assert_eq!(find_cursor_report(b"\x1b[A\x1b[3;7R"), Some((3, 9, (2, 6))));
+        assert_eq!(find_cursor_report(b"\x1b\x1b[3;7R"), Some((1, 7, (2, 6))));
+    }
 
-        String::from_utf8(output.output).unwrap()
+    #[test]
+    fn test_waits_for_complete_cursor_reports() {
+        assert_eq!(find_cursor_report(b""), None);
+        assert_eq!(find_cursor_report(b"\x1b[12;"), None);
+        assert_eq!(find_cursor_report(b"\x1b[12;5"), None);
+        assert_eq!(find_cursor_report(b"\x1b[12R"), None);
     }
 }
@@ -1,7 +1,7 @@
-use libc::{c_int, c_ulong, c_ushort, STDOUT_FILENO};
-use libc::funcs::bsd44::ioctl;
+use libc::{c_ushort, ioctl, TIOCGWINSZ};
 
-use std::io::{IoResult, standard_error, ResourceUnavailable};
+use std::io;
+use std::os::unix::io::RawFd;
 
 #[repr(C)]
 struct winsize {
@@ -11,23 +11,44 @@ struct winsize {
     ws_ypixel: c_ushort   /* vertical size, pixels */
 }
 
-const TIOCGWINSZ: c_ulong = 0x40087468;
-
-pub fn winsize() -> IoResult<(u16, u16)> {
-    let w = winsize { ws_row: 0, ws_col: 0, ws_xpixel: 0, ws_ypixel: 0 };
-    let r = unsafe { ioctl(STDOUT_FILENO, TIOCGWINSZ, &w) };    
+/// Ask the terminal behind `fd` for its size, as (width, height).
+///
+/// `fd` should be the terminal itself rather than stdout, which is usually
+/// a pipe when selecta runs inside `$(...)`.
+pub fn winsize(fd: RawFd) -> io::Result<(u16, u16)> {
+    let mut w = winsize { ws_row: 0, ws_col: 0, ws_xpixel: 0, ws_ypixel: 0 };
+    let r = unsafe { ioctl(fd, TIOCGWINSZ, &mut w) };
 
     match r {
-        0 => Ok((w.ws_col as u16, w.ws_row as u16)),
-        _ => {
-            return Err(standard_error(ResourceUnavailable))
-        }
+        0 if w.ws_col > 0 && w.ws_row > 0 => Ok((w.ws_col, w.ws_row)),
+        0 => Err(io::Error::other("terminal reported a size of zero")),
+        _ => Err(io::Error::last_os_error()),
     }
 }
 
-#[test]
-fn test_winsize_has_valid_width_and_height() {
-    let (width, height) = winsize().unwrap();
-    assert!(width > 0);
-    assert!(height > 0);
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::fs::File;
+    use std::os::unix::io::AsRawFd;
+
+    #[test]
+    fn test_winsize_has_valid_width_and_height() {
+        // Only meaningful with a controlling terminal, e.g. not under CI.
+        let tty = match File::open("/dev/tty") {
+            Ok(tty) => tty,
+            Err(_) => return,
+        };
+
+        let (width, height) = winsize(tty.as_raw_fd()).unwrap();
+        assert!(width > 0);
+        assert!(height > 0);
+    }
+
+    #[test]
+    fn test_winsize_fails_for_non_terminals() {
+        let file = File::open("/dev/null").unwrap();
+        assert!(winsize(file.as_raw_fd()).is_err());
+    }
 }
@@ -0,0 +1,54 @@
+//! Checks the default scorer's rankings of real paths against the
+//! expectations in tests/fixtures/rankings.txt.
+
+use selecta::query::Query;
+use selecta::score::Selecta;
+use selecta::search::compute_matches;
+
+static PATHS: &str = include_str!("fixtures/paths.txt");
+static RANKINGS: &str = include_str!("fixtures/rankings.txt");
+
+/// Each query with the matches it must rank first.
+fn expectations() -> Vec<(&'static str, Vec<&'static str>)> {
+    let mut expectations: Vec<(&str, Vec<&str>)> = Vec::new();
+
+    for line in RANKINGS.lines() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        match expectations.last_mut() {
+            Some((_, expected)) if line.starts_with(' ') => expected.push(line.trim()),
+            _ => expectations.push((line, Vec::new())),
+        }
+    }
+
+    expectations
+}
+
+#[test]
+fn test_ranks_paths_as_expected() {
+    let paths: Vec<String> = PATHS.lines().map(|line| line.to_string()).collect();
+    let expectations = expectations();
+    assert!(!expectations.is_empty());
+
+    let failures: Vec<String> = expectations.iter().filter_map(|(query, expected)| {
+        let matches = compute_matches(&paths, &Query::parse(query), &Selecta);
+        let top: Vec<&str> = matches.iter().take(expected.len()).map(|m| m.as_str()).collect();
+
+        if top == *expected {
+            None
+        } else {
+            Some(format!("{:?}: expected {:?}, got {:?}", query, expected, top))
+        }
+    }).collect();
+
+    assert!(failures.is_empty(), "rankings changed:\n{}", failures.join("\n"));
+}
+
+#[test]
+fn test_every_query_has_expectations() {
+    for (query, expected) in expectations() {
+        assert!(!expected.is_empty(), "no expected matches for {:?}", query);
+    }
+}